@@ -26,6 +26,54 @@ pub struct CgroupConfig {
     /// The metrics config for the cgroup(s). This includes the label map and namespace.
     #[serde(default)]
     pub metrics: MetricsConfig,
+    /// Additional cgroup interface files to export metrics from, for files that have no built-in
+    /// support, such as `misc.current` or `rdma.current`.
+    #[serde(default)]
+    pub files: Vec<InterfaceFile>,
+}
+
+/// A cgroup interface file to export metrics from.
+/// The metric name is derived from the file name, e.g. `misc.current` becomes `misc_current`.
+#[derive(Debug, Clone, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct InterfaceFile {
+    /// The name of the file in the cgroup directory, such as `misc.current`. It can't contain `/`.
+    /// On cgroup v1, the file is looked up under the hierarchy of the controller named by the
+    /// part before the first dot, e.g. `misc`, and `cgroup.*` files are read from any hierarchy
+    /// of the cgroup.
+    pub file: String,
+    /// How the content of the file is parsed.
+    #[serde(default)]
+    pub format: InterfaceFileFormat,
+    /// The type of the metric.
+    #[serde(default)]
+    pub metric_type: InterfaceFileMetricType,
+    /// The help text of the metric.
+    pub help: Option<String>,
+}
+
+/// The format of a cgroup interface file.
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum InterfaceFileFormat {
+    /// A single value, such as `pids.current`. A value of `max` is omitted.
+    #[default]
+    Single,
+    /// One `key value` pair per line, such as `misc.current`. The key becomes the `key` label.
+    FlatKeyed,
+    /// One `key sub_key=value ...` line per key, such as `rdma.current`. The key becomes the
+    /// `key` label, and the sub key becomes the `field` label.
+    NestedKeyed,
+}
+
+/// The type of the metric exported from a cgroup interface file.
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum InterfaceFileMetricType {
+    #[default]
+    Gauge,
+    /// The metric name gets a `_total` suffix.
+    Counter,
 }
 
 /// The config for a process. This includes the matcher and the metrics config.
@@ -52,6 +100,10 @@ pub struct MetricsConfig {
     /// For example, if the namespace is `my_service`, the metrics will be prefixed with `my_service_`
     /// instead of `process_` or `cgroup_`.
     pub namespace: Option<String>,
+    /// Whether to read `/proc/<pid>/smaps_rollup` of each process to export the proportional (PSS),
+    /// unique (USS) and shared memory usage. This is expensive for processes with many mappings.
+    #[serde(default)]
+    pub smaps_rollup: bool,
 }
 
 /// A matcher for cgroups. It can match a single cgroup or a group of cgroups.
@@ -240,8 +292,23 @@ mod tests {
                             label_map: vec![("name".to_string(), "name".to_string())]
                                 .into_iter()
                                 .collect(),
-                            namespace: Some("my_services".to_string())
-                        }
+                            namespace: Some("my_services".to_string()),
+                            smaps_rollup: false,
+                        },
+                        files: vec![
+                            InterfaceFile {
+                                file: "misc.current".to_string(),
+                                format: InterfaceFileFormat::FlatKeyed,
+                                metric_type: InterfaceFileMetricType::Gauge,
+                                help: Some("Current usage of misc resources".to_string()),
+                            },
+                            InterfaceFile {
+                                file: "rdma.current".to_string(),
+                                format: InterfaceFileFormat::NestedKeyed,
+                                metric_type: InterfaceFileMetricType::Gauge,
+                                help: None,
+                            },
+                        ]
                     },
                     CgroupConfig {
                         match_by: CgroupMatch {
@@ -260,8 +327,10 @@ mod tests {
                             label_map: vec![("name".to_string(), "name".to_string())]
                                 .into_iter()
                                 .collect(),
-                            namespace: Some("container".to_string())
-                        }
+                            namespace: Some("container".to_string()),
+                            smaps_rollup: false,
+                        },
+                        files: vec![]
                     },
                     CgroupConfig {
                         match_by: CgroupMatch {
@@ -279,8 +348,10 @@ mod tests {
                             label_map: vec![("name".to_string(), "id".to_string())]
                                 .into_iter()
                                 .collect(),
-                            namespace: Some("container".to_string())
-                        }
+                            namespace: Some("container".to_string()),
+                            smaps_rollup: false,
+                        },
+                        files: vec![]
                     }
                 ],
                 processes: vec![ProcessConfig {
@@ -292,7 +363,8 @@ mod tests {
                         label_map: vec![("name".to_string(), "name".to_string())]
                             .into_iter()
                             .collect(),
-                        namespace: Some("my_services".to_string())
+                        namespace: Some("my_services".to_string()),
+                        smaps_rollup: true,
                     }
                 }],
                 shell_commands: ShellCommandsConfig {
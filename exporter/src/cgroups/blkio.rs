@@ -0,0 +1,197 @@
+use cgroups_rs::blkio::IoService;
+use serde::Serialize;
+
+use crate::{cgroups::io::device_labels, render::LabeledSample};
+
+/// The totals and weights of the v1 blkio controller. The per-device entries are exported as
+/// samples labelled by device and operation, see `blkio_samples`.
+#[derive(Serialize, Default)]
+pub struct BlkIo {
+    pub io_merged_total: u64,
+    pub io_merged_recursive_total: u64,
+    pub io_queued_total: u64,
+    pub io_queued_recursive_total: u64,
+    pub io_service_bytes_total: u64,
+    pub io_service_bytes_recursive_total: u64,
+    pub io_serviced_total: u64,
+    pub io_serviced_recursive_total: u64,
+    pub io_service_time_total: u64,
+    pub io_service_time_recursive_total: u64,
+    pub io_wait_time_total: u64,
+    pub io_wait_time_recursive_total: u64,
+    pub leaf_weight: u64,
+    pub throttle: BlkIoThrottle,
+    pub weight: u64,
+}
+
+#[derive(Serialize, Default)]
+pub struct BlkIoThrottle {
+    pub io_service_bytes_total: u64,
+    pub io_service_bytes_recursive_total: u64,
+    pub io_serviced_total: u64,
+    pub io_serviced_recursive_total: u64,
+}
+
+impl From<&cgroups_rs::blkio::BlkIo> for BlkIo {
+    fn from(blkio: &cgroups_rs::blkio::BlkIo) -> Self {
+        Self {
+            io_merged_total: blkio.io_merged_total,
+            io_merged_recursive_total: blkio.io_merged_recursive_total,
+            io_queued_total: blkio.io_queued_total,
+            io_queued_recursive_total: blkio.io_queued_recursive_total,
+            io_service_bytes_total: blkio.io_service_bytes_total,
+            io_service_bytes_recursive_total: blkio.io_service_bytes_recursive_total,
+            io_serviced_total: blkio.io_serviced_total,
+            io_serviced_recursive_total: blkio.io_serviced_recursive_total,
+            io_service_time_total: blkio.io_service_time_total,
+            io_service_time_recursive_total: blkio.io_service_time_recursive_total,
+            io_wait_time_total: blkio.io_wait_time_total,
+            io_wait_time_recursive_total: blkio.io_wait_time_recursive_total,
+            leaf_weight: blkio.leaf_weight,
+            throttle: BlkIoThrottle {
+                io_service_bytes_total: blkio.throttle.io_service_bytes_total,
+                io_service_bytes_recursive_total: blkio.throttle.io_service_bytes_recursive_total,
+                io_serviced_total: blkio.throttle.io_serviced_total,
+                io_serviced_recursive_total: blkio.throttle.io_serviced_recursive_total,
+            },
+            weight: blkio.weight,
+        }
+    }
+}
+
+/// Exports each per-device entry of the v1 blkio controller as samples labelled by `device`,
+/// and by `op` for the entries that are broken down by operation.
+pub fn blkio_samples(blkio: &cgroups_rs::blkio::BlkIo) -> Vec<LabeledSample> {
+    let mut samples = Vec::new();
+    let throttle = &blkio.throttle;
+    for (metric, services) in [
+        ("blkio_device_io_merged", &blkio.io_merged),
+        (
+            "blkio_device_io_merged_recursive",
+            &blkio.io_merged_recursive,
+        ),
+        ("blkio_device_io_queued", &blkio.io_queued),
+        (
+            "blkio_device_io_queued_recursive",
+            &blkio.io_queued_recursive,
+        ),
+        ("blkio_device_io_service_bytes", &blkio.io_service_bytes),
+        (
+            "blkio_device_io_service_bytes_recursive",
+            &blkio.io_service_bytes_recursive,
+        ),
+        ("blkio_device_io_serviced", &blkio.io_serviced),
+        (
+            "blkio_device_io_serviced_recursive",
+            &blkio.io_serviced_recursive,
+        ),
+        ("blkio_device_io_service_time", &blkio.io_service_time),
+        (
+            "blkio_device_io_service_time_recursive",
+            &blkio.io_service_time_recursive,
+        ),
+        ("blkio_device_io_wait_time", &blkio.io_wait_time),
+        (
+            "blkio_device_io_wait_time_recursive",
+            &blkio.io_wait_time_recursive,
+        ),
+        (
+            "blkio_device_throttle_io_service_bytes",
+            &throttle.io_service_bytes,
+        ),
+        (
+            "blkio_device_throttle_io_service_bytes_recursive",
+            &throttle.io_service_bytes_recursive,
+        ),
+        ("blkio_device_throttle_io_serviced", &throttle.io_serviced),
+        (
+            "blkio_device_throttle_io_serviced_recursive",
+            &throttle.io_serviced_recursive,
+        ),
+    ] {
+        for service in services {
+            push_io_service(&mut samples, metric, service);
+        }
+    }
+    for (metric, data) in [
+        ("blkio_device_sectors", &blkio.sectors),
+        ("blkio_device_sectors_recursive", &blkio.sectors_recursive),
+        ("blkio_device_time", &blkio.time),
+        ("blkio_device_time_recursive", &blkio.time_recursive),
+        ("blkio_device_weight", &blkio.weight_device),
+        ("blkio_device_leaf_weight", &blkio.leaf_weight_device),
+        ("blkio_device_throttle_read_bps", &throttle.read_bps_device),
+        (
+            "blkio_device_throttle_read_iops",
+            &throttle.read_iops_device,
+        ),
+        (
+            "blkio_device_throttle_write_bps",
+            &throttle.write_bps_device,
+        ),
+        (
+            "blkio_device_throttle_write_iops",
+            &throttle.write_iops_device,
+        ),
+    ] {
+        for entry in data {
+            let labels = device_labels(&format!("{}:{}", entry.major, entry.minor));
+            samples.push(LabeledSample::new(metric, labels, entry.data));
+        }
+    }
+    samples
+}
+
+fn push_io_service(samples: &mut Vec<LabeledSample>, metric: &'static str, service: &IoService) {
+    let labels = device_labels(&format!("{}:{}", service.major, service.minor));
+    for (op, value) in [
+        ("Read", service.read),
+        ("Write", service.write),
+        ("Sync", service.sync),
+        ("Async", service.r#async),
+        ("Discard", service.discard),
+        ("Total", service.total),
+    ] {
+        let mut labels = labels.clone();
+        labels.push(("op", op.to_string()));
+        samples.push(LabeledSample::new(metric, labels, value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn io_service_op_labels() {
+        let service = IoService {
+            major: 8,
+            minor: 0,
+            read: 1,
+            write: 2,
+            sync: 3,
+            r#async: 4,
+            discard: 5,
+            total: 6,
+        };
+        let mut samples = Vec::new();
+        push_io_service(&mut samples, "blkio_device_io_serviced", &service);
+        let sample = |op: &str, value: u64| {
+            let mut labels = device_labels("8:0");
+            labels.push(("op", op.to_string()));
+            LabeledSample::new("blkio_device_io_serviced", labels, value)
+        };
+        assert_eq!(
+            samples,
+            vec![
+                sample("Read", 1),
+                sample("Write", 2),
+                sample("Sync", 3),
+                sample("Async", 4),
+                sample("Discard", 5),
+                sample("Total", 6),
+            ]
+        );
+    }
+}
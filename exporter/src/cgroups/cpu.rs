@@ -0,0 +1,332 @@
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use cgroups_rs::{Cgroup, cpuacct::CpuAcct};
+use serde::Serialize;
+
+use crate::{
+    cgroups::{
+        files::{CgroupDir, parse_limit},
+        metrics::CpuStat,
+    },
+    render::LabeledSample,
+};
+
+const NANOSECONDS_PER_SECOND: f64 = 1_000_000_000.0;
+
+/// Scrapes closer to the previous one than this reuse its rates, so that a cgroup that is matched
+/// by several configs does not get rates computed over a few microseconds.
+const MIN_RATE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Counters of cgroups that have not been scraped for this long are forgotten.
+const STALE_AFTER: Duration = Duration::from_secs(600);
+
+/// The CPU counters of each cgroup at its previous scrape, keyed by cgroup path.
+static HISTORY: LazyLock<Mutex<CpuHistory>> = LazyLock::new(Mutex::default);
+
+/// The configured CPU limits, from `cpu.max` and `cpu.weight` on v2, or from
+/// `cpu.cfs_quota_us`, `cpu.cfs_period_us` and `cpu.shares` on v1.
+#[derive(Serialize, Default)]
+pub struct CpuLimit {
+    /// `None` if there is no quota.
+    pub quota_usec: Option<u64>,
+    pub period_usec: Option<u64>,
+    /// Only available on v2.
+    pub weight: Option<u64>,
+    /// Only available on v1.
+    pub shares: Option<u64>,
+    /// The quota divided by the period, i.e. how many CPUs worth of time the cgroup may use.
+    pub cores: Option<f64>,
+}
+
+impl CpuLimit {
+    pub fn read(cgroup: &Cgroup) -> Self {
+        let Some(dir) = CgroupDir::for_controller(cgroup, "cpu") else {
+            return Self::default();
+        };
+        let mut limit = if cgroup.v2() {
+            let (quota_usec, period_usec) = dir
+                .read("cpu.max")
+                .ok()
+                .map(|content| parse_cpu_max(&content))
+                .unwrap_or_default();
+            Self {
+                quota_usec,
+                period_usec,
+                weight: dir.read_value("cpu.weight"),
+                ..Default::default()
+            }
+        } else {
+            Self {
+                // The quota is -1 when there is no limit.
+                quota_usec: dir
+                    .read_value::<i64>("cpu.cfs_quota_us")
+                    .and_then(|quota| u64::try_from(quota).ok()),
+                period_usec: dir.read_value("cpu.cfs_period_us"),
+                shares: dir.read_value("cpu.shares"),
+                ..Default::default()
+            }
+        };
+        limit.cores = limit.quota_cores();
+        limit
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn quota_cores(&self) -> Option<f64> {
+        match (self.quota_usec, self.period_usec) {
+            (Some(quota), Some(period)) if period > 0 => Some(quota as f64 / period as f64),
+            _ => None,
+        }
+    }
+}
+
+/// Parses `cpu.max`, which looks like `$MAX $PERIOD`, where `$MAX` may be `max`.
+fn parse_cpu_max(content: &str) -> (Option<u64>, Option<u64>) {
+    let mut parts = content.split_whitespace();
+    let quota = parts.next().and_then(parse_limit);
+    let period = parts.next().and_then(|period| period.parse().ok());
+    (quota, period)
+}
+
+/// Per-CPU usage from `cpuacct.usage_percpu`, `cpuacct.usage_percpu_user` and
+/// `cpuacct.usage_percpu_sys` (v1 only), as samples labelled by CPU.
+pub fn percpu_samples(cpuacct: &CpuAcct) -> Vec<LabeledSample> {
+    let mut samples = Vec::new();
+    for (metric, usage) in [
+        ("cpu_usage_seconds", &cpuacct.usage_percpu),
+        ("cpu_user_seconds", &cpuacct.usage_percpu_user),
+        ("cpu_system_seconds", &cpuacct.usage_percpu_sys),
+    ] {
+        samples.extend(parse_percpu(metric, usage));
+    }
+    samples
+}
+
+/// Each file holds the usage of every CPU in nanoseconds, separated by spaces.
+fn parse_percpu(metric: &'static str, usage: &str) -> impl Iterator<Item = LabeledSample> {
+    usage
+        .split_whitespace()
+        .enumerate()
+        .filter_map(move |(cpu, nanoseconds)| {
+            let nanoseconds = nanoseconds.parse::<u64>().ok()?;
+            #[allow(clippy::cast_precision_loss)]
+            let seconds = nanoseconds as f64 / NANOSECONDS_PER_SECOND;
+            Some(LabeledSample::new(
+                metric,
+                vec![("cpu", cpu.to_string())],
+                seconds,
+            ))
+        })
+}
+
+/// Gauges derived from the change of the CPU counters since the previous scrape of the cgroup.
+/// Both are `None` on the first scrape and when the cgroup has no CPU quota.
+#[derive(Clone, Copy, Default)]
+pub struct CpuRates {
+    /// The share of enforcement periods in which the cgroup was throttled.
+    pub throttled_ratio: Option<f64>,
+    /// The CPU usage divided by the quota, where 1 means the whole quota is used.
+    pub limit_utilization: Option<f64>,
+}
+
+impl CpuRates {
+    /// Computes the rates from `cpu.stat`, which has the same period counters on v1 and v2, and
+    /// the total CPU usage of the cgroup.
+    pub fn compute(
+        cgroup: &Cgroup,
+        limit: &CpuLimit,
+        stat: &CpuStat,
+        usage_usec: Option<u64>,
+    ) -> Self {
+        let (Some(cores), Some(nr_periods), Some(nr_throttled), Some(usage_usec)) =
+            (limit.cores, stat.nr_periods, stat.nr_throttled, usage_usec)
+        else {
+            return Self::default();
+        };
+        let current = CpuCounters {
+            at: Instant::now(),
+            nr_periods,
+            nr_throttled,
+            usage_usec,
+        };
+        HISTORY
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .rates(cgroup.path(), current, cores)
+    }
+}
+
+#[derive(Default)]
+struct CpuHistory {
+    previous: HashMap<String, (CpuCounters, CpuRates)>,
+    pruned_at: Option<Instant>,
+}
+
+impl CpuHistory {
+    fn rates(&mut self, key: &str, current: CpuCounters, cores: f64) -> CpuRates {
+        self.prune(current.at);
+        let rates = match self.previous.get(key) {
+            Some((previous, rates)) if current.at - previous.at < MIN_RATE_INTERVAL => {
+                return *rates;
+            }
+            Some((previous, _)) => current.rates_since(previous, cores),
+            None => CpuRates::default(),
+        };
+        self.previous.insert(key.to_string(), (current, rates));
+        rates
+    }
+
+    fn prune(&mut self, now: Instant) {
+        if self
+            .pruned_at
+            .is_some_and(|pruned_at| now - pruned_at < STALE_AFTER)
+        {
+            return;
+        }
+        self.previous
+            .retain(|_, (counters, _)| now - counters.at < STALE_AFTER);
+        self.pruned_at = Some(now);
+    }
+}
+
+/// The counters from `cpu.stat` and the CPU usage that the rates are computed from.
+struct CpuCounters {
+    at: Instant,
+    nr_periods: u64,
+    nr_throttled: u64,
+    usage_usec: u64,
+}
+
+impl CpuCounters {
+    /// The counters may go backwards if the cgroup was recreated, so the differences saturate.
+    #[allow(clippy::cast_precision_loss)]
+    fn rates_since(&self, previous: &Self, cores: f64) -> CpuRates {
+        let periods = self.nr_periods.saturating_sub(previous.nr_periods);
+        let throttled = self.nr_throttled.saturating_sub(previous.nr_throttled);
+        let usage_usec = self.usage_usec.saturating_sub(previous.usage_usec);
+        let elapsed_usec = (self.at - previous.at).as_micros() as f64;
+        CpuRates {
+            // No periods pass while the cgroup has nothing to run, so it was not throttled.
+            throttled_ratio: Some(if periods > 0 {
+                throttled as f64 / periods as f64
+            } else {
+                0.0
+            }),
+            limit_utilization: (cores > 0.0).then(|| usage_usec as f64 / elapsed_usec / cores),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn counters(at: Instant, nr_periods: u64, nr_throttled: u64, usage_usec: u64) -> CpuCounters {
+        CpuCounters {
+            at,
+            nr_periods,
+            nr_throttled,
+            usage_usec,
+        }
+    }
+
+    #[test]
+    fn parse_cpu_max_values() {
+        assert_eq!(parse_cpu_max("max 100000\n"), (None, Some(100_000)));
+        assert_eq!(
+            parse_cpu_max("50000 100000\n"),
+            (Some(50_000), Some(100_000))
+        );
+    }
+
+    #[test]
+    fn parse_percpu_usage() {
+        let samples: Vec<_> =
+            parse_percpu("cpu_user_seconds", "1500000000 0 250000000 \n").collect();
+        let sample = |cpu: &str, seconds: f64| {
+            LabeledSample::new("cpu_user_seconds", vec![("cpu", cpu.to_string())], seconds)
+        };
+        assert_eq!(
+            samples,
+            vec![sample("0", 1.5), sample("1", 0.0), sample("2", 0.25)]
+        );
+    }
+
+    #[test]
+    fn rates_since_previous() {
+        let start = Instant::now();
+        let previous = counters(start, 100, 10, 1_000_000);
+        let current = counters(start + Duration::from_secs(2), 120, 15, 2_000_000);
+        let rates = current.rates_since(&previous, 2.0);
+        assert_eq!(rates.throttled_ratio, Some(0.25));
+        assert_eq!(rates.limit_utilization, Some(0.25));
+    }
+
+    #[test]
+    fn rates_since_idle_or_reset() {
+        let start = Instant::now();
+        let previous = counters(start, 100, 10, 1_000_000);
+        let idle = counters(start + Duration::from_secs(2), 100, 10, 1_000_000);
+        let rates = idle.rates_since(&previous, 1.0);
+        assert_eq!(rates.throttled_ratio, Some(0.0));
+        assert_eq!(rates.limit_utilization, Some(0.0));
+
+        // A recreated cgroup starts its counters over.
+        let reset = counters(start + Duration::from_secs(2), 5, 1, 1_000);
+        let rates = reset.rates_since(&previous, 1.0);
+        assert_eq!(rates.throttled_ratio, Some(0.0));
+        assert_eq!(rates.limit_utilization, Some(0.0));
+    }
+
+    #[test]
+    fn history_reuses_recent_rates() {
+        let start = Instant::now();
+        let mut history = CpuHistory::default();
+        let first = history.rates("a", counters(start, 100, 10, 0), 1.0);
+        assert_eq!(first.throttled_ratio, None);
+
+        let second = history.rates(
+            "a",
+            counters(start + Duration::from_secs(2), 110, 15, 1_000_000),
+            1.0,
+        );
+        assert_eq!(second.throttled_ratio, Some(0.5));
+        assert_eq!(second.limit_utilization, Some(0.5));
+
+        // Too close to the previous scrape, so the rates are reused and the counters are kept.
+        let third = history.rates(
+            "a",
+            counters(start + Duration::from_millis(2500), 200, 100, 2_000_000),
+            1.0,
+        );
+        assert_eq!(third.throttled_ratio, Some(0.5));
+        assert_eq!(third.limit_utilization, Some(0.5));
+        assert_eq!(history.previous["a"].0.nr_periods, 110);
+    }
+
+    #[test]
+    fn history_forgets_stale_cgroups() {
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+        let mut history = CpuHistory::default();
+        history.rates("a", counters(at(0), 0, 0, 0), 1.0);
+        history.rates("b", counters(at(60), 0, 0, 0), 1.0);
+
+        history.rates("c", counters(at(630), 0, 0, 0), 1.0);
+        assert!(!history.previous.contains_key("a"));
+        assert_eq!(history.previous.len(), 2);
+
+        // Pruning runs at most once per `STALE_AFTER`, so `b` is kept for now.
+        history.rates("c", counters(at(690), 0, 0, 0), 1.0);
+        assert_eq!(history.previous.len(), 2);
+
+        history.rates("c", counters(at(1260), 0, 0, 0), 1.0);
+        assert_eq!(history.previous.len(), 1);
+        assert!(history.previous.contains_key("c"));
+    }
+}
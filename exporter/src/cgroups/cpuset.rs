@@ -0,0 +1,96 @@
+use cgroups_rs::Cgroup;
+use serde::Serialize;
+
+use crate::{cgroups::files::CgroupDir, render::LabeledSample};
+
+/// The CPUs and memory nodes that the cgroup is allowed to use.
+#[derive(Serialize, Default)]
+pub struct CpuSet {
+    pub cpus_effective_count: Option<u64>,
+    pub mems_effective_count: Option<u64>,
+    /// The raw lists, such as `0-3,8`. Exported through `info` instead of as numbers.
+    #[serde(skip)]
+    pub cpus_effective: Option<String>,
+    #[serde(skip)]
+    pub mems_effective: Option<String>,
+}
+
+impl CpuSet {
+    pub fn read(cgroup: &Cgroup) -> Self {
+        let Some(dir) = CgroupDir::for_controller(cgroup, "cpuset") else {
+            return Self::default();
+        };
+        let (cpus_file, mems_file) = if cgroup.v2() {
+            ("cpuset.cpus.effective", "cpuset.mems.effective")
+        } else {
+            ("cpuset.effective_cpus", "cpuset.effective_mems")
+        };
+        let cpus_effective = dir.read(cpus_file).ok().map(|cpus| cpus.trim().to_string());
+        let mems_effective = dir.read(mems_file).ok().map(|mems| mems.trim().to_string());
+        Self {
+            cpus_effective_count: cpus_effective.as_deref().map(count_list),
+            mems_effective_count: mems_effective.as_deref().map(count_list),
+            cpus_effective,
+            mems_effective,
+        }
+    }
+
+    /// An info sample that carries the effective CPU and memory node lists as labels. `None` if
+    /// neither list could be read.
+    pub fn info(&self) -> Option<LabeledSample> {
+        if self.cpus_effective.is_none() && self.mems_effective.is_none() {
+            return None;
+        }
+        Some(LabeledSample::new(
+            "cpuset_info",
+            vec![
+                ("cpus", self.cpus_effective.clone().unwrap_or_default()),
+                ("mems", self.mems_effective.clone().unwrap_or_default()),
+            ],
+            1u64,
+        ))
+    }
+}
+
+/// Counts the entries in a list such as `0-3,8,10-11`.
+fn count_list(list: &str) -> u64 {
+    list.split(',')
+        .filter(|part| !part.is_empty())
+        .map(|part| match part.split_once('-') {
+            Some((start, end)) => match (start.parse::<u64>(), end.parse::<u64>()) {
+                (Ok(start), Ok(end)) if end >= start => end - start + 1,
+                _ => 0,
+            },
+            None => u64::from(part.parse::<u64>().is_ok()),
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_cpu_list() {
+        assert_eq!(count_list("0-3,8,10-11"), 7);
+        assert_eq!(count_list("0"), 1);
+        assert_eq!(count_list(""), 0);
+    }
+
+    #[test]
+    fn info_needs_a_list() {
+        assert_eq!(CpuSet::default().info(), None);
+        let cpuset = CpuSet {
+            cpus_effective: Some("0-3".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            cpuset.info(),
+            Some(LabeledSample::new(
+                "cpuset_info",
+                vec![("cpus", "0-3".to_string()), ("mems", String::new())],
+                1u64,
+            ))
+        );
+    }
+}
@@ -9,7 +9,7 @@ use tracing::{debug, error};
 
 use crate::{
     cgroups::metrics::CgroupMetrics,
-    matcher::{CgroupMatcher, MatchableCgroupConfig, NameMatcher},
+    matcher::{CgroupMatcher, MatchableCgroupConfig, MatchableInterfaceFile, NameMatcher},
     render::MatchGroup,
     shell::ShellEvaluator,
 };
@@ -62,7 +62,12 @@ async fn discover_cgroup_metrics(
         return;
     };
     let _join_res = tokio::task::spawn_blocking(move || {
-        let series_result = discover_cgroup_metrics_blocking(&config.match_by, &evaluator);
+        let series_result = discover_cgroup_metrics_blocking(
+            &config.match_by,
+            &config.files,
+            config.metrics.smaps_rollup,
+            &evaluator,
+        );
         let _ = sender.blocking_send(series_result.map(|cgroups| {
             let mut metrics_config = config.metrics;
             if metrics_config.namespace.is_none() {
@@ -76,6 +81,8 @@ async fn discover_cgroup_metrics(
 
 fn discover_cgroup_metrics_blocking(
     matcher: &CgroupMatcher,
+    files: &[MatchableInterfaceFile],
+    smaps_rollup: bool,
     evaluator: &ShellEvaluator,
 ) -> anyhow::Result<Vec<CgroupMetrics>> {
     let cgroups_iter = discover_cgroup_for_match_blocking(matcher)
@@ -87,7 +94,13 @@ fn discover_cgroup_metrics_blocking(
 
     let metrics = cgroups_iter
         .filter_map(|cgroup| {
-            match CgroupMetrics::from_cgroup_blocking(&cgroup, matcher, evaluator) {
+            match CgroupMetrics::from_cgroup_blocking(
+                &cgroup,
+                matcher,
+                files,
+                smaps_rollup,
+                evaluator,
+            ) {
                 Ok(answer) => Some(answer),
                 Err(err) => {
                     // Logging at the debug level to avoid cluttering the logs in case of many cgroups
@@ -0,0 +1,231 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use cgroups_rs::{Cgroup, Controller};
+
+/// The mount point of the unified (v2) hierarchy, which is also where cgroups-rs expects it.
+/// Only used for v2 cgroups that have no controllers to take the directory from.
+const UNIFIED_ROOT: &str = "/sys/fs/cgroup";
+
+/// The directory of a cgroup, used to read interface files that cgroups-rs does not expose.
+pub struct CgroupDir {
+    path: PathBuf,
+}
+
+impl CgroupDir {
+    /// The directory of a controller of the cgroup, as resolved by cgroups-rs from the mount
+    /// table.
+    pub fn of(controller: &dyn Controller) -> Self {
+        Self {
+            path: controller.path().to_path_buf(),
+        }
+    }
+
+    /// The directory of the cgroup in the unified (v2) hierarchy.
+    pub fn unified(cgroup: &Cgroup) -> Self {
+        // All controllers of a v2 cgroup share its directory.
+        match cgroup.subsystems().first() {
+            Some(subsystem) => Self::of(subsystem.to_controller()),
+            None => Self {
+                path: Path::new(UNIFIED_ROOT).join(cgroup.path().trim_start_matches('/')),
+            },
+        }
+    }
+
+    /// The directory of the cgroup for a controller, such as `cpu`: the unified directory on v2,
+    /// or the directory in the hierarchy that the controller is mounted on v1. `None` if the
+    /// controller is not mounted on v1.
+    pub fn for_controller(cgroup: &Cgroup, controller: &str) -> Option<Self> {
+        if cgroup.v2() {
+            return Some(Self::unified(cgroup));
+        }
+        let subsystems = cgroup.subsystems();
+        // The core `cgroup.*` files exist in every v1 hierarchy.
+        if controller == "cgroup" {
+            return subsystems
+                .first()
+                .map(|subsystem| Self::of(subsystem.to_controller()));
+        }
+        subsystems
+            .iter()
+            .find(|subsystem| subsystem.controller_name() == controller)
+            .map(|subsystem| Self::of(subsystem.to_controller()))
+            .or_else(|| Self::from_mountinfo(cgroup, controller))
+    }
+
+    /// The directory of the cgroup in the v1 hierarchy of a controller that cgroups-rs does not
+    /// support, such as `misc`, found in the mount table.
+    fn from_mountinfo(cgroup: &Cgroup, controller: &str) -> Option<Self> {
+        let mountinfo = fs::read_to_string("/proc/self/mountinfo").ok()?;
+        let (root, mount_point) = parse_v1_mount(&mountinfo, controller)?;
+        let path = Path::new("/").join(cgroup.path());
+        let relative = path.strip_prefix(root).unwrap_or(&path);
+        Some(Self {
+            path: Path::new(mount_point).join(relative.strip_prefix("/").unwrap_or(relative)),
+        })
+    }
+
+    /// The metadata of the directory itself.
+    pub fn metadata(&self) -> io::Result<fs::Metadata> {
+        fs::metadata(&self.path)
+    }
+
+    /// The metadata of an interface file in this directory.
+    pub fn file_metadata(&self, file: &str) -> io::Result<fs::Metadata> {
+        fs::metadata(self.path.join(file))
+    }
+
+    /// Reads the whole content of an interface file in this directory.
+    pub fn read(&self, file: &str) -> io::Result<String> {
+        fs::read_to_string(self.path.join(file))
+    }
+
+    /// Reads a file that holds a single value, such as `pids.current`.
+    pub fn read_value<T: FromStr>(&self, file: &str) -> Option<T> {
+        self.read(file).ok()?.trim().parse().ok()
+    }
+
+    /// Reads the value of a single key from a flat keyed file, such as `max` from `pids.events`.
+    pub fn read_keyed_value(&self, file: &str, key: &str) -> Option<u64> {
+        let content = self.read(file).ok()?;
+        parse_flat_keyed(&content)
+            .find(|(k, _)| *k == key)
+            .and_then(|(_, value)| value.trim().parse().ok())
+    }
+
+    /// Reads a file that holds a single limit, such as `pids.max`. Returns `None` if the file
+    /// cannot be read or if there is no limit.
+    pub fn read_limit(&self, file: &str) -> Option<u64> {
+        parse_limit(&self.read(file).ok()?)
+    }
+
+    /// Reads a v1 limit in bytes of a counter that is charged in units of `unit_bytes`, such as
+    /// `hugetlb.2MB.limit_in_bytes`. Returns `None` if the file cannot be read or if there is no
+    /// limit.
+    pub fn read_v1_limit(&self, file: &str, unit_bytes: u64) -> Option<u64> {
+        parse_v1_limit(&self.read(file).ok()?, unit_bytes)
+    }
+}
+
+/// Parses a limit value, where `max` means that there is no limit.
+pub fn parse_limit(value: &str) -> Option<u64> {
+    match value.trim() {
+        "max" => None,
+        value => value.parse().ok(),
+    }
+}
+
+/// Parses a v1 limit value in bytes. When there is no limit, v1 reports the page counter maximum,
+/// which is `LONG_MAX` rounded down to whole units of the counter, e.g. to whole huge pages for
+/// hugetlb. So anything within one unit of `LONG_MAX` means that there is no limit.
+pub fn parse_v1_limit(value: &str, unit_bytes: u64) -> Option<u64> {
+    let unlimited = (u64::MAX >> 1).saturating_sub(unit_bytes);
+    value
+        .trim()
+        .parse()
+        .ok()
+        .filter(|limit| *limit <= unlimited)
+}
+
+/// Finds the root and the mount point of the v1 hierarchy that a controller is mounted on, in
+/// `/proc/self/mountinfo`. The lines look like
+/// `35 25 0:30 / /sys/fs/cgroup/misc rw,relatime shared:14 - cgroup cgroup rw,misc`.
+fn parse_v1_mount<'a>(mountinfo: &'a str, controller: &str) -> Option<(&'a str, &'a str)> {
+    mountinfo.lines().find_map(|line| {
+        let (mount, filesystem) = line.split_once(" - ")?;
+        let mut filesystem = filesystem.split_whitespace();
+        if filesystem.next()? != "cgroup" {
+            return None;
+        }
+        let options = filesystem.nth(1)?;
+        if !options.split(',').any(|option| option == controller) {
+            return None;
+        }
+        let mut mount = mount.split_whitespace().skip(3);
+        Some((mount.next()?, mount.next()?))
+    })
+}
+
+/// Parses a flat keyed file such as `memory.events`, where each line looks like `key value`.
+pub fn parse_flat_keyed(content: &str) -> impl Iterator<Item = (&str, &str)> {
+    content.lines().filter_map(|line| line.split_once(' '))
+}
+
+/// Parses a nested keyed file such as `io.stat` or `cpu.pressure`, where each line looks like
+/// `key sub_key=value sub_key=value ...`.
+pub fn parse_nested_keyed(content: &str) -> impl Iterator<Item = (&str, Vec<(&str, &str)>)> {
+    content.lines().filter_map(|line| {
+        let mut parts = line.split_whitespace();
+        let key = parts.next()?;
+        let fields = parts.filter_map(|part| part.split_once('=')).collect();
+        Some((key, fields))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_v1_unlimited() {
+        const MB: u64 = 1024 * 1024;
+        assert_eq!(parse_v1_limit("9223372036854771712\n", 4096), None);
+        assert_eq!(parse_v1_limit("9223372036854710272\n", 64 * 1024), None);
+        assert_eq!(parse_v1_limit("9223372036852678656\n", 2 * MB), None);
+        assert_eq!(parse_v1_limit("9223372035781033984\n", 1024 * MB), None);
+        assert_eq!(parse_v1_limit("2147483648\n", 2 * MB), Some(2_147_483_648));
+    }
+
+    #[test]
+    fn parse_v1_mounts() {
+        let mountinfo = "\
+25 1 0:23 / /sys/fs/cgroup ro,nosuid shared:9 - tmpfs tmpfs ro,mode=755
+26 25 0:24 / /sys/fs/cgroup/cpu,cpuacct rw,nosuid shared:10 - cgroup cgroup rw,cpu,cpuacct
+35 25 0:30 /kubepods /sys/fs/cgroup/misc rw,relatime shared:14 - cgroup cgroup rw,misc
+";
+        assert_eq!(
+            parse_v1_mount(mountinfo, "cpuacct"),
+            Some(("/", "/sys/fs/cgroup/cpu,cpuacct"))
+        );
+        assert_eq!(
+            parse_v1_mount(mountinfo, "misc"),
+            Some(("/kubepods", "/sys/fs/cgroup/misc"))
+        );
+        assert_eq!(parse_v1_mount(mountinfo, "cpu,cpuacct"), None);
+        assert_eq!(parse_v1_mount(mountinfo, "rdma"), None);
+    }
+
+    #[test]
+    fn parse_nested_keyed_lines() {
+        let content = "some avg10=0.12 avg60=0.00 avg300=0.00 total=1234\n\
+                       full avg10=0.00 avg60=0.00 avg300=0.00 total=56\n";
+        let parsed: Vec<_> = parse_nested_keyed(content).collect();
+        assert_eq!(
+            parsed,
+            vec![
+                (
+                    "some",
+                    vec![
+                        ("avg10", "0.12"),
+                        ("avg60", "0.00"),
+                        ("avg300", "0.00"),
+                        ("total", "1234")
+                    ]
+                ),
+                (
+                    "full",
+                    vec![
+                        ("avg10", "0.00"),
+                        ("avg60", "0.00"),
+                        ("avg300", "0.00"),
+                        ("total", "56")
+                    ]
+                ),
+            ]
+        );
+    }
+}
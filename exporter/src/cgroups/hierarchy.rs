@@ -0,0 +1,37 @@
+//! Metrics from the core interface files of cgroup v2, which exist regardless of the enabled
+//! controllers.
+use serde::Serialize;
+
+use crate::cgroups::files::CgroupDir;
+
+/// Counters from `cgroup.stat`.
+#[derive(Serialize, Default)]
+pub struct CgroupStat {
+    pub nr_descendants: Option<u64>,
+    pub nr_dying_descendants: Option<u64>,
+}
+
+impl CgroupStat {
+    pub fn read(dir: &CgroupDir) -> Self {
+        Self {
+            nr_descendants: dir.read_keyed_value("cgroup.stat", "nr_descendants"),
+            nr_dying_descendants: dir.read_keyed_value("cgroup.stat", "nr_dying_descendants"),
+        }
+    }
+}
+
+/// State flags from `cgroup.events`, either 0 or 1.
+#[derive(Serialize, Default)]
+pub struct CgroupEvents {
+    pub populated: Option<u64>,
+    pub frozen: Option<u64>,
+}
+
+impl CgroupEvents {
+    pub fn read(dir: &CgroupDir) -> Self {
+        Self {
+            populated: dir.read_keyed_value("cgroup.events", "populated"),
+            frozen: dir.read_keyed_value("cgroup.events", "frozen"),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use cgroups_rs::Cgroup;
+
+use crate::{cgroups::files::CgroupDir, render::LabeledSample};
+
+/// Reads the hugetlb usage and limits for each page size, such as `2MB` and `1GB`.
+pub fn hugetlb_samples(cgroup: &Cgroup, page_sizes: &[String]) -> Vec<LabeledSample> {
+    let Some(dir) = CgroupDir::for_controller(cgroup, "hugetlb") else {
+        return Vec::new();
+    };
+    let mut samples = Vec::new();
+    for page_size in page_sizes {
+        let labels = vec![("pagesize", page_size.clone())];
+        let mut push = |metric: &'static str, value: Option<u64>| {
+            if let Some(value) = value {
+                samples.push(LabeledSample::new(metric, labels.clone(), value));
+            }
+        };
+        if cgroup.v2() {
+            push(
+                "hugetlb_usage_bytes",
+                dir.read_value(&format!("hugetlb.{page_size}.current")),
+            );
+            push(
+                "hugetlb_limit_bytes",
+                dir.read_limit(&format!("hugetlb.{page_size}.max")),
+            );
+            // v2 has no fail counter, but the `max` event is counted on the same condition.
+            push(
+                "hugetlb_failcnt",
+                dir.read_keyed_value(&format!("hugetlb.{page_size}.events"), "max"),
+            );
+        } else {
+            push(
+                "hugetlb_usage_bytes",
+                dir.read_value(&format!("hugetlb.{page_size}.usage_in_bytes")),
+            );
+            push(
+                "hugetlb_limit_bytes",
+                parse_page_size(page_size).and_then(|page_size_bytes| {
+                    dir.read_v1_limit(
+                        &format!("hugetlb.{page_size}.limit_in_bytes"),
+                        page_size_bytes,
+                    )
+                }),
+            );
+            push(
+                "hugetlb_max_usage_bytes",
+                dir.read_value(&format!("hugetlb.{page_size}.max_usage_in_bytes")),
+            );
+            push(
+                "hugetlb_failcnt",
+                dir.read_value(&format!("hugetlb.{page_size}.failcnt")),
+            );
+        }
+    }
+    samples
+}
+
+/// Parses a page size as it appears in the names of the hugetlb files, such as `2MB` or `1GB`.
+fn parse_page_size(page_size: &str) -> Option<u64> {
+    let (number, unit) = page_size.split_at(page_size.find(|c: char| !c.is_ascii_digit())?);
+    let unit_bytes: u64 = match unit {
+        "KB" => 1 << 10,
+        "MB" => 1 << 20,
+        "GB" => 1 << 30,
+        _ => return None,
+    };
+    number.parse::<u64>().ok()?.checked_mul(unit_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_page_sizes() {
+        assert_eq!(parse_page_size("64KB"), Some(64 * 1024));
+        assert_eq!(parse_page_size("2MB"), Some(2 * 1024 * 1024));
+        assert_eq!(parse_page_size("1GB"), Some(1024 * 1024 * 1024));
+        assert_eq!(parse_page_size("2XB"), None);
+        assert_eq!(parse_page_size("MB"), None);
+    }
+}
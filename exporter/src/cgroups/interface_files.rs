@@ -0,0 +1,137 @@
+use cgroups_exporter_config::InterfaceFileFormat;
+use cgroups_rs::Cgroup;
+
+use crate::{
+    cgroups::files::{CgroupDir, parse_flat_keyed, parse_nested_keyed},
+    matcher::MatchableInterfaceFile,
+    render::{LabeledSample, SampleValue},
+};
+
+/// Reads the cgroup interface files listed in the config. Files that don't exist for the cgroup
+/// are skipped.
+pub fn interface_file_samples(
+    cgroup: &Cgroup,
+    files: &[MatchableInterfaceFile],
+) -> Vec<LabeledSample> {
+    let mut samples = Vec::new();
+    for file in files {
+        let controller = file.file.split('.').next().unwrap_or_default();
+        let Some(dir) = CgroupDir::for_controller(cgroup, controller) else {
+            continue;
+        };
+        let Ok(content) = dir.read(&file.file) else {
+            continue;
+        };
+        samples.extend(parse_interface_file(file, &content));
+    }
+    samples
+}
+
+fn parse_interface_file(file: &MatchableInterfaceFile, content: &str) -> Vec<LabeledSample> {
+    let mut samples = Vec::new();
+    match file.format {
+        InterfaceFileFormat::Single => {
+            if let Some(value) = parse_value(content) {
+                samples.push(LabeledSample::new(file.metric, vec![], value));
+            }
+        }
+        InterfaceFileFormat::FlatKeyed => {
+            for (key, value) in parse_flat_keyed(content) {
+                if let Some(value) = parse_value(value) {
+                    let labels = vec![("key", key.to_string())];
+                    samples.push(LabeledSample::new(file.metric, labels, value));
+                }
+            }
+        }
+        InterfaceFileFormat::NestedKeyed => {
+            for (key, fields) in parse_nested_keyed(content) {
+                for (field, value) in fields {
+                    if let Some(value) = parse_value(value) {
+                        let labels = vec![("key", key.to_string()), ("field", field.to_string())];
+                        samples.push(LabeledSample::new(file.metric, labels, value));
+                    }
+                }
+            }
+        }
+    }
+    samples
+}
+
+/// Parses an integer or a decimal value. `max` is treated as no value.
+fn parse_value(value: &str) -> Option<SampleValue> {
+    let value = value.trim();
+    if value == "max" {
+        return None;
+    }
+    value
+        .parse::<u64>()
+        .map(SampleValue::from)
+        .or_else(|_| value.parse::<f64>().map(SampleValue::from))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use cgroups_exporter_config::InterfaceFileMetricType;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn interface_file(file: &str, format: InterfaceFileFormat) -> MatchableInterfaceFile {
+        MatchableInterfaceFile {
+            file: file.to_string(),
+            format,
+            metric_type: InterfaceFileMetricType::Gauge,
+            metric: "test_metric",
+            rename: None,
+            help: "",
+        }
+    }
+
+    #[test]
+    fn parse_values() {
+        assert_eq!(parse_value("42\n"), Some(SampleValue::Int(42)));
+        assert_eq!(parse_value("0.25"), Some(SampleValue::Float(0.25)));
+        assert_eq!(parse_value("-1"), Some(SampleValue::Float(-1.0)));
+        assert_eq!(parse_value("max"), None);
+        assert_eq!(parse_value("some"), None);
+    }
+
+    #[test]
+    fn parse_single() {
+        let file = interface_file("memory.high", InterfaceFileFormat::Single);
+        assert_eq!(
+            parse_interface_file(&file, "1024\n"),
+            vec![LabeledSample::new("test_metric", vec![], 1024u64)]
+        );
+        assert_eq!(parse_interface_file(&file, "max\n"), vec![]);
+    }
+
+    #[test]
+    fn parse_flat_keyed_file() {
+        let file = interface_file("memory.events", InterfaceFileFormat::FlatKeyed);
+        let content = "low 0\nhigh 12\nmax max\nratio 0.5\n";
+        assert_eq!(
+            parse_interface_file(&file, content),
+            vec![
+                LabeledSample::new("test_metric", vec![("key", "low".to_string())], 0u64),
+                LabeledSample::new("test_metric", vec![("key", "high".to_string())], 12u64),
+                LabeledSample::new("test_metric", vec![("key", "ratio".to_string())], 0.5),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_nested_keyed_file() {
+        let file = interface_file("io.max", InterfaceFileFormat::NestedKeyed);
+        let content = "8:0 rbps=1048576 wbps=max avg10=0.10\n";
+        let labels = |field: &str| vec![("key", "8:0".to_string()), ("field", field.to_string())];
+        assert_eq!(
+            parse_interface_file(&file, content),
+            vec![
+                LabeledSample::new("test_metric", labels("rbps"), 1_048_576u64),
+                LabeledSample::new("test_metric", labels("avg10"), 0.10),
+            ]
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use std::{fs, path::Path};
+
+use crate::{
+    cgroups::files::{CgroupDir, parse_limit, parse_nested_keyed},
+    render::LabeledSample,
+};
+
+/// The keys of `io.stat` that are exported, and their metric names.
+const IO_STAT_METRICS: [(&str, &str); 6] = [
+    ("rbytes", "io_stat_rbytes"),
+    ("wbytes", "io_stat_wbytes"),
+    ("rios", "io_stat_rios"),
+    ("wios", "io_stat_wios"),
+    ("dbytes", "io_stat_dbytes"),
+    ("dios", "io_stat_dios"),
+];
+
+/// The keys of `io.max` that are exported, and their metric names.
+const IO_MAX_METRICS: [(&str, &str); 4] = [
+    ("rbps", "io_max_rbps"),
+    ("wbps", "io_max_wbps"),
+    ("riops", "io_max_riops"),
+    ("wiops", "io_max_wiops"),
+];
+
+/// Reads the per-device I/O statistics from `io.stat` (v2 only).
+pub fn io_stat_samples(dir: &CgroupDir) -> Vec<LabeledSample> {
+    dir.read("io.stat")
+        .map(|content| parse_io_stat(&content))
+        .unwrap_or_default()
+}
+
+fn parse_io_stat(content: &str) -> Vec<LabeledSample> {
+    let mut samples = Vec::new();
+    for (device, fields) in parse_nested_keyed(content) {
+        let labels = device_labels(device);
+        for (key, value) in fields {
+            let Some((_, metric)) = IO_STAT_METRICS.iter().find(|(k, _)| *k == key) else {
+                continue;
+            };
+            if let Ok(value) = value.parse::<u64>() {
+                samples.push(LabeledSample::new(*metric, labels.clone(), value));
+            }
+        }
+    }
+    samples
+}
+
+/// Reads the configured per-device I/O limits from `io.max`, `io.weight` and `io.latency`
+/// (v2 only). Limits that are set to `max` are omitted.
+pub fn io_limit_samples(dir: &CgroupDir) -> Vec<LabeledSample> {
+    let mut samples = Vec::new();
+    if let Ok(content) = dir.read("io.max") {
+        samples.extend(parse_io_max(&content));
+    }
+    if let Ok(content) = dir.read("io.weight") {
+        samples.extend(parse_io_weight(&content));
+    }
+    if let Ok(content) = dir.read("io.latency") {
+        samples.extend(parse_io_latency(&content));
+    }
+    samples
+}
+
+fn parse_io_max(content: &str) -> Vec<LabeledSample> {
+    let mut samples = Vec::new();
+    for (device, fields) in parse_nested_keyed(content) {
+        let labels = device_labels(device);
+        for (key, value) in fields {
+            let Some((_, metric)) = IO_MAX_METRICS.iter().find(|(k, _)| *k == key) else {
+                continue;
+            };
+            if let Some(value) = parse_limit(value) {
+                samples.push(LabeledSample::new(*metric, labels.clone(), value));
+            }
+        }
+    }
+    samples
+}
+
+/// The first line is the default weight, such as `default 100`, then one line per device that
+/// overrides it, such as `8:0 200`.
+fn parse_io_weight(content: &str) -> Vec<LabeledSample> {
+    let mut samples = Vec::new();
+    for line in content.lines() {
+        let Some((key, value)) = line.split_once(' ') else {
+            continue;
+        };
+        let Ok(value) = value.trim().parse::<u64>() else {
+            continue;
+        };
+        if key == "default" {
+            samples.push(LabeledSample::new("io_weight_default", vec![], value));
+        } else {
+            samples.push(LabeledSample::new("io_weight", device_labels(key), value));
+        }
+    }
+    samples
+}
+
+fn parse_io_latency(content: &str) -> Vec<LabeledSample> {
+    let mut samples = Vec::new();
+    for (device, fields) in parse_nested_keyed(content) {
+        let target = fields
+            .iter()
+            .find(|(key, _)| *key == "target")
+            .and_then(|(_, value)| value.parse::<u64>().ok());
+        if let Some(target) = target {
+            samples.push(LabeledSample::new(
+                "io_latency_target_usec",
+                device_labels(device),
+                target,
+            ));
+        }
+    }
+    samples
+}
+
+/// Labels for a block device identified by its `major:minor` numbers. The device name is
+/// resolved from `/sys/dev/block`, falling back to the numbers if the device is gone.
+pub fn device_labels(major_minor: &str) -> Vec<(&'static str, String)> {
+    let device = device_name(major_minor).unwrap_or_else(|| major_minor.to_string());
+    vec![("device", device), ("major_minor", major_minor.to_string())]
+}
+
+fn device_name(major_minor: &str) -> Option<String> {
+    let link = fs::read_link(Path::new("/sys/dev/block").join(major_minor)).ok()?;
+    Some(link.file_name()?.to_string_lossy().into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_io_stat_lines() {
+        let content = "8:0 rbytes=1024 wbytes=2048 rios=3 wios=4 dbytes=0 dios=0\n\
+                       253:1 rbytes=512 wbytes=0 rios=1 wios=0 dbytes=8 dios=2 cost.usage=5\n";
+        let sample =
+            |metric, device, value: u64| LabeledSample::new(metric, device_labels(device), value);
+        assert_eq!(
+            parse_io_stat(content),
+            vec![
+                sample("io_stat_rbytes", "8:0", 1024),
+                sample("io_stat_wbytes", "8:0", 2048),
+                sample("io_stat_rios", "8:0", 3),
+                sample("io_stat_wios", "8:0", 4),
+                sample("io_stat_dbytes", "8:0", 0),
+                sample("io_stat_dios", "8:0", 0),
+                sample("io_stat_rbytes", "253:1", 512),
+                sample("io_stat_wbytes", "253:1", 0),
+                sample("io_stat_rios", "253:1", 1),
+                sample("io_stat_wios", "253:1", 0),
+                sample("io_stat_dbytes", "253:1", 8),
+                sample("io_stat_dios", "253:1", 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_io_max_lines() {
+        let content = "8:0 rbps=1048576 wbps=max riops=max wiops=100\n\
+                       8:16 rbps=max wbps=max riops=max wiops=max\n";
+        assert_eq!(
+            parse_io_max(content),
+            vec![
+                LabeledSample::new("io_max_rbps", device_labels("8:0"), 1_048_576u64),
+                LabeledSample::new("io_max_wiops", device_labels("8:0"), 100u64),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_io_weight_lines() {
+        let content = "default 100\n8:0 200\n";
+        assert_eq!(
+            parse_io_weight(content),
+            vec![
+                LabeledSample::new("io_weight_default", vec![], 100u64),
+                LabeledSample::new("io_weight", device_labels("8:0"), 200u64),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_io_latency_lines() {
+        let content = "8:0 target=75000\n8:16 target=max\n";
+        assert_eq!(
+            parse_io_latency(content),
+            vec![LabeledSample::new(
+                "io_latency_target_usec",
+                device_labels("8:0"),
+                75_000u64
+            )]
+        );
+    }
+}
@@ -0,0 +1,111 @@
+//! Metrics about the state of the cgroup itself rather than the resources it uses.
+use std::{fs, os::unix::fs::MetadataExt as _, time::UNIX_EPOCH};
+
+use cgroups_rs::Cgroup;
+
+use crate::{cgroups::files::CgroupDir, render::LabeledSample};
+
+/// The states of the freezer, in the order they are exported.
+const FREEZER_STATES: [&str; 3] = ["thawed", "freezing", "frozen"];
+
+/// Exports the freezer state as a state set: one sample per state, where the current state is 1
+/// and the others are 0. Returns nothing if the freezer is not available.
+pub fn freezer_samples(cgroup: &Cgroup) -> Vec<LabeledSample> {
+    let Some(current) = freezer_state(cgroup) else {
+        return Vec::new();
+    };
+    FREEZER_STATES
+        .iter()
+        .map(|state| {
+            LabeledSample::new(
+                "freezer_state",
+                vec![("state", (*state).to_string())],
+                u64::from(*state == current),
+            )
+        })
+        .collect()
+}
+
+fn freezer_state(cgroup: &Cgroup) -> Option<&'static str> {
+    if !cgroup.v2() {
+        let dir = CgroupDir::for_controller(cgroup, "freezer")?;
+        return parse_v1_freezer_state(&dir.read("freezer.state").ok()?);
+    }
+    // `cgroup.freeze` is the requested state, and `frozen` in `cgroup.events` tells whether the
+    // freezing has completed.
+    let dir = CgroupDir::unified(cgroup);
+    let requested = dir.read_value::<u64>("cgroup.freeze")?;
+    let frozen = dir.read_keyed_value("cgroup.events", "frozen").unwrap_or(0);
+    Some(v2_freezer_state(requested, frozen))
+}
+
+fn v2_freezer_state(requested: u64, frozen: u64) -> &'static str {
+    match (requested, frozen) {
+        (0, _) => "thawed",
+        (_, 0) => "freezing",
+        _ => "frozen",
+    }
+}
+
+fn parse_v1_freezer_state(content: &str) -> Option<&'static str> {
+    match content.trim() {
+        "THAWED" => Some("thawed"),
+        "FREEZING" => Some("freezing"),
+        "FROZEN" => Some("frozen"),
+        _ => None,
+    }
+}
+
+/// The time the cgroup was created, in seconds since the epoch. kernfs updates the times of a
+/// cgroup directory whenever a child cgroup is added or removed, so like cAdvisor, the earlier of
+/// the directory time and the time of a file that is created with the cgroup and rarely changes
+/// is taken.
+pub fn created_timestamp(cgroup: &Cgroup) -> Option<f64> {
+    let (dir, file) = if cgroup.v2() {
+        (CgroupDir::unified(cgroup), "cgroup.events")
+    } else {
+        // The cgroup has a directory in each v1 hierarchy, created at about the same time.
+        let dir = cgroup
+            .subsystems()
+            .iter()
+            .map(|subsystem| CgroupDir::of(subsystem.to_controller()))
+            .find(|dir| dir.metadata().is_ok())?;
+        (dir, "cgroup.clone_children")
+    };
+    [dir.metadata(), dir.file_metadata(file)]
+        .into_iter()
+        .filter_map(|metadata| timestamp(&metadata.ok()?))
+        .reduce(f64::min)
+}
+
+/// The birth time of a file, or its change time if the filesystem does not report it.
+#[allow(clippy::cast_precision_loss)]
+fn timestamp(metadata: &fs::Metadata) -> Option<f64> {
+    match metadata.created() {
+        Ok(created) => Some(created.duration_since(UNIX_EPOCH).ok()?.as_secs_f64()),
+        Err(_) => Some(metadata.ctime() as f64 + metadata.ctime_nsec() as f64 / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_v1_freezer_states() {
+        assert_eq!(parse_v1_freezer_state("THAWED\n"), Some("thawed"));
+        assert_eq!(parse_v1_freezer_state("FREEZING\n"), Some("freezing"));
+        assert_eq!(parse_v1_freezer_state("FROZEN\n"), Some("frozen"));
+        assert_eq!(parse_v1_freezer_state(""), None);
+    }
+
+    #[test]
+    fn v2_freezer_states() {
+        assert_eq!(v2_freezer_state(0, 0), "thawed");
+        // The cgroup may still be frozen right after it was asked to thaw.
+        assert_eq!(v2_freezer_state(0, 1), "thawed");
+        assert_eq!(v2_freezer_state(1, 0), "freezing");
+        assert_eq!(v2_freezer_state(1, 1), "frozen");
+    }
+}
@@ -0,0 +1,354 @@
+use cgroups_rs::Cgroup;
+use serde::Serialize;
+
+use crate::{
+    cgroups::files::{CgroupDir, parse_flat_keyed},
+    render::LabeledSample,
+};
+
+/// Event counters from `memory.events` or `memory.events.local` (v2 only).
+#[derive(Serialize, Default)]
+pub struct MemoryEvents {
+    pub low: Option<u64>,
+    pub high: Option<u64>,
+    pub max: Option<u64>,
+    pub oom: Option<u64>,
+    pub oom_kill: Option<u64>,
+    pub oom_group_kill: Option<u64>,
+}
+
+impl MemoryEvents {
+    pub fn read(dir: &CgroupDir, file: &str) -> Option<Self> {
+        let content = dir.read(file).ok()?;
+        let mut events = Self::default();
+        for (key, value) in parse_flat_keyed(&content) {
+            let value = value.trim().parse().ok();
+            match key {
+                "low" => events.low = value,
+                "high" => events.high = value,
+                "max" => events.max = value,
+                "oom" => events.oom = value,
+                "oom_kill" => events.oom_kill = value,
+                "oom_group_kill" => events.oom_group_kill = value,
+                _ => {}
+            }
+        }
+        Some(events)
+    }
+}
+
+/// Event counters from `memory.swap.events` (v2 only).
+#[derive(Serialize, Default)]
+pub struct SwapEvents {
+    pub high: Option<u64>,
+    pub max: Option<u64>,
+    pub fail: Option<u64>,
+}
+
+impl SwapEvents {
+    pub fn read(dir: &CgroupDir) -> Option<Self> {
+        let content = dir.read("memory.swap.events").ok()?;
+        let mut events = Self::default();
+        for (key, value) in parse_flat_keyed(&content) {
+            let value = value.trim().parse().ok();
+            match key {
+                "high" => events.high = value,
+                "max" => events.max = value,
+                "fail" => events.fail = value,
+                _ => {}
+            }
+        }
+        Some(events)
+    }
+}
+
+/// Statistics from `memory.stat` with the keys used by cgroup v2. Sizes are in bytes, the rest
+/// are event counters.
+#[derive(Serialize, Default)]
+pub struct MemoryStatV2 {
+    pub anon: Option<u64>,
+    pub file: Option<u64>,
+    pub kernel: Option<u64>,
+    pub kernel_stack: Option<u64>,
+    pub pagetables: Option<u64>,
+    pub sec_pagetables: Option<u64>,
+    pub percpu: Option<u64>,
+    pub sock: Option<u64>,
+    pub vmalloc: Option<u64>,
+    pub shmem: Option<u64>,
+    pub zswap: Option<u64>,
+    pub zswapped: Option<u64>,
+    pub file_mapped: Option<u64>,
+    pub file_dirty: Option<u64>,
+    pub file_writeback: Option<u64>,
+    pub swapcached: Option<u64>,
+    pub anon_thp: Option<u64>,
+    pub file_thp: Option<u64>,
+    pub shmem_thp: Option<u64>,
+    pub inactive_anon: Option<u64>,
+    pub active_anon: Option<u64>,
+    pub inactive_file: Option<u64>,
+    pub active_file: Option<u64>,
+    pub unevictable: Option<u64>,
+    pub slab_reclaimable: Option<u64>,
+    pub slab_unreclaimable: Option<u64>,
+    pub slab: Option<u64>,
+    pub hugetlb: Option<u64>,
+    pub workingset_refault_anon: Option<u64>,
+    pub workingset_refault_file: Option<u64>,
+    pub workingset_activate_anon: Option<u64>,
+    pub workingset_activate_file: Option<u64>,
+    pub workingset_restore_anon: Option<u64>,
+    pub workingset_restore_file: Option<u64>,
+    pub workingset_nodereclaim: Option<u64>,
+    pub pgscan: Option<u64>,
+    pub pgscan_kswapd: Option<u64>,
+    pub pgscan_direct: Option<u64>,
+    pub pgscan_khugepaged: Option<u64>,
+    pub pgsteal: Option<u64>,
+    pub pgsteal_kswapd: Option<u64>,
+    pub pgsteal_direct: Option<u64>,
+    pub pgsteal_khugepaged: Option<u64>,
+    pub pgfault: Option<u64>,
+    pub pgmajfault: Option<u64>,
+    pub pgrefill: Option<u64>,
+    pub pgactivate: Option<u64>,
+    pub pgdeactivate: Option<u64>,
+    pub pglazyfree: Option<u64>,
+    pub pglazyfreed: Option<u64>,
+    pub pgdemote_kswapd: Option<u64>,
+    pub pgdemote_direct: Option<u64>,
+    pub pgdemote_khugepaged: Option<u64>,
+    pub zswpin: Option<u64>,
+    pub zswpout: Option<u64>,
+    pub zswpwb: Option<u64>,
+    pub thp_fault_alloc: Option<u64>,
+    pub thp_collapse_alloc: Option<u64>,
+    pub thp_swpout: Option<u64>,
+    pub thp_swpout_fallback: Option<u64>,
+    pub numa_pages_migrated: Option<u64>,
+    pub numa_pte_updates: Option<u64>,
+    pub numa_hint_faults: Option<u64>,
+}
+
+impl MemoryStatV2 {
+    pub fn read(dir: &CgroupDir) -> Option<Self> {
+        let content = dir.read("memory.stat").ok()?;
+        let mut stat = Self::default();
+        for (key, value) in parse_flat_keyed(&content) {
+            let value = value.trim().parse().ok();
+            match key {
+                "anon" => stat.anon = value,
+                "file" => stat.file = value,
+                "kernel" => stat.kernel = value,
+                "kernel_stack" => stat.kernel_stack = value,
+                "pagetables" => stat.pagetables = value,
+                "sec_pagetables" => stat.sec_pagetables = value,
+                "percpu" => stat.percpu = value,
+                "sock" => stat.sock = value,
+                "vmalloc" => stat.vmalloc = value,
+                "shmem" => stat.shmem = value,
+                "zswap" => stat.zswap = value,
+                "zswapped" => stat.zswapped = value,
+                "file_mapped" => stat.file_mapped = value,
+                "file_dirty" => stat.file_dirty = value,
+                "file_writeback" => stat.file_writeback = value,
+                "swapcached" => stat.swapcached = value,
+                "anon_thp" => stat.anon_thp = value,
+                "file_thp" => stat.file_thp = value,
+                "shmem_thp" => stat.shmem_thp = value,
+                "inactive_anon" => stat.inactive_anon = value,
+                "active_anon" => stat.active_anon = value,
+                "inactive_file" => stat.inactive_file = value,
+                "active_file" => stat.active_file = value,
+                "unevictable" => stat.unevictable = value,
+                "slab_reclaimable" => stat.slab_reclaimable = value,
+                "slab_unreclaimable" => stat.slab_unreclaimable = value,
+                "slab" => stat.slab = value,
+                "hugetlb" => stat.hugetlb = value,
+                "workingset_refault_anon" => stat.workingset_refault_anon = value,
+                "workingset_refault_file" => stat.workingset_refault_file = value,
+                "workingset_activate_anon" => stat.workingset_activate_anon = value,
+                "workingset_activate_file" => stat.workingset_activate_file = value,
+                "workingset_restore_anon" => stat.workingset_restore_anon = value,
+                "workingset_restore_file" => stat.workingset_restore_file = value,
+                "workingset_nodereclaim" => stat.workingset_nodereclaim = value,
+                "pgscan" => stat.pgscan = value,
+                "pgscan_kswapd" => stat.pgscan_kswapd = value,
+                "pgscan_direct" => stat.pgscan_direct = value,
+                "pgscan_khugepaged" => stat.pgscan_khugepaged = value,
+                "pgsteal" => stat.pgsteal = value,
+                "pgsteal_kswapd" => stat.pgsteal_kswapd = value,
+                "pgsteal_direct" => stat.pgsteal_direct = value,
+                "pgsteal_khugepaged" => stat.pgsteal_khugepaged = value,
+                "pgfault" => stat.pgfault = value,
+                "pgmajfault" => stat.pgmajfault = value,
+                "pgrefill" => stat.pgrefill = value,
+                "pgactivate" => stat.pgactivate = value,
+                "pgdeactivate" => stat.pgdeactivate = value,
+                "pglazyfree" => stat.pglazyfree = value,
+                "pglazyfreed" => stat.pglazyfreed = value,
+                "pgdemote_kswapd" => stat.pgdemote_kswapd = value,
+                "pgdemote_direct" => stat.pgdemote_direct = value,
+                "pgdemote_khugepaged" => stat.pgdemote_khugepaged = value,
+                "zswpin" => stat.zswpin = value,
+                "zswpout" => stat.zswpout = value,
+                "zswpwb" => stat.zswpwb = value,
+                "thp_fault_alloc" => stat.thp_fault_alloc = value,
+                "thp_collapse_alloc" => stat.thp_collapse_alloc = value,
+                "thp_swpout" => stat.thp_swpout = value,
+                "thp_swpout_fallback" => stat.thp_swpout_fallback = value,
+                "numa_pages_migrated" => stat.numa_pages_migrated = value,
+                "numa_pte_updates" => stat.numa_pte_updates = value,
+                "numa_hint_faults" => stat.numa_hint_faults = value,
+                _ => {}
+            }
+        }
+        Some(stat)
+    }
+}
+
+/// Usage and limits of swap or zswap (v2 only), in bytes.
+#[derive(Serialize, Default)]
+pub struct SwapUsage {
+    pub current: Option<u64>,
+    /// `None` if there is no limit.
+    pub max: Option<u64>,
+    pub peak: Option<u64>,
+}
+
+impl SwapUsage {
+    pub fn read_swap(dir: &CgroupDir) -> Self {
+        Self {
+            current: dir.read_value("memory.swap.current"),
+            max: dir.read_limit("memory.swap.max"),
+            peak: dir.read_value("memory.swap.peak"),
+        }
+    }
+
+    pub fn read_zswap(dir: &CgroupDir) -> Self {
+        Self {
+            current: dir.read_value("memory.zswap.current"),
+            max: dir.read_limit("memory.zswap.max"),
+            // The kernel does not track a peak for zswap.
+            peak: None,
+        }
+    }
+}
+
+/// Memory protections and limits (v2 only), in bytes. Limits that are set to `max` are `None`.
+#[derive(Serialize, Default)]
+pub struct MemoryLimit {
+    pub min: Option<u64>,
+    pub low: Option<u64>,
+    pub high: Option<u64>,
+    pub max: Option<u64>,
+}
+
+impl MemoryLimit {
+    pub fn read(dir: &CgroupDir) -> Self {
+        Self {
+            min: dir.read_limit("memory.min"),
+            low: dir.read_limit("memory.low"),
+            high: dir.read_limit("memory.high"),
+            max: dir.read_limit("memory.max"),
+        }
+    }
+}
+
+/// Reads `memory.numa_stat` as samples labelled by NUMA node and memory type. v1 reports pages,
+/// and `hierarchical_` entries get the `hierarchy` scope. v2 reports bytes, except for the
+/// `workingset_` entries, which are event counters.
+pub fn numa_stat_samples(cgroup: &Cgroup) -> Vec<LabeledSample> {
+    CgroupDir::for_controller(cgroup, "memory")
+        .and_then(|dir| dir.read("memory.numa_stat").ok())
+        .map(|content| parse_numa_stat(&content, cgroup.v2()))
+        .unwrap_or_default()
+}
+
+fn parse_numa_stat(content: &str, v2: bool) -> Vec<LabeledSample> {
+    let mut samples = Vec::new();
+    for line in content.lines() {
+        let mut parts = line.split_whitespace();
+        let Some(first) = parts.next() else {
+            continue;
+        };
+        // v1 lines look like `total=10 N0=6 N1=4`, v2 lines look like `anon N0=6 N1=4`.
+        let kind = first.split_once('=').map_or(first, |(kind, _)| kind);
+        let (metric, scope, kind) = if v2 && kind.starts_with("workingset_") {
+            ("memory_numa_events", None, kind)
+        } else if v2 {
+            ("memory_numa_bytes", None, kind)
+        } else if let Some(kind) = kind.strip_prefix("hierarchical_") {
+            ("memory_numa_pages", Some("hierarchy"), kind)
+        } else {
+            ("memory_numa_pages", Some("container"), kind)
+        };
+        for (node, value) in parts.filter_map(|part| part.split_once('=')) {
+            let (Some(node), Ok(value)) = (node.strip_prefix('N'), value.parse::<u64>()) else {
+                continue;
+            };
+            let mut labels = vec![("node", node.to_string()), ("type", kind.to_string())];
+            if let Some(scope) = scope {
+                labels.push(("scope", scope.to_string()));
+            }
+            samples.push(LabeledSample::new(metric, labels, value));
+        }
+    }
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn numa_sample(metric: &'static str, node: &str, kind: &str, value: u64) -> LabeledSample {
+        LabeledSample::new(
+            metric,
+            vec![("node", node.to_string()), ("type", kind.to_string())],
+            value,
+        )
+    }
+
+    #[test]
+    fn parse_v1_numa_stat() {
+        let content = "total=10 N0=6 N1=4\n\
+                       hierarchical_anon=3 N0=1 N1=2\n";
+        let scoped = |node: &str, kind: &str, scope: &str, value: u64| {
+            LabeledSample::new(
+                "memory_numa_pages",
+                vec![
+                    ("node", node.to_string()),
+                    ("type", kind.to_string()),
+                    ("scope", scope.to_string()),
+                ],
+                value,
+            )
+        };
+        assert_eq!(
+            parse_numa_stat(content, false),
+            vec![
+                scoped("0", "total", "container", 6),
+                scoped("1", "total", "container", 4),
+                scoped("0", "anon", "hierarchy", 1),
+                scoped("1", "anon", "hierarchy", 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_v2_numa_stat() {
+        let content = "anon N0=4096 N1=8192\n\
+                       workingset_refault_file N0=3 N1=0\n";
+        assert_eq!(
+            parse_numa_stat(content, true),
+            vec![
+                numa_sample("memory_numa_bytes", "0", "anon", 4096),
+                numa_sample("memory_numa_bytes", "1", "anon", 8192),
+                numa_sample("memory_numa_events", "0", "workingset_refault_file", 3),
+                numa_sample("memory_numa_events", "1", "workingset_refault_file", 0),
+            ]
+        );
+    }
+}
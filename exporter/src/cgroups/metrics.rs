@@ -3,11 +3,13 @@ use std::collections::HashMap;
 use anyhow::Context as _;
 use cgroups_rs::{
     Cgroup,
-    blkio::{BlkIo, BlkIoController},
+    blkio::BlkIoController,
     cpu::CpuController,
     cpuacct::{CpuAcct, CpuAcctController},
-    cpuset::CpuSet,
+    cpuset::CpuSetController,
+    hugetlb::HugeTlbController,
     memory::{MemController, Memory},
+    pid::PidController,
 };
 use new_string_template::template::Template;
 use procfs::process::Process;
@@ -15,9 +17,26 @@ use saturating_cast::SaturatingCast as _;
 use serde::Serialize;
 
 use crate::{
-    matcher::{CgroupMatcher, NameMatcher},
+    cgroups::{
+        blkio::{BlkIo, blkio_samples},
+        cpu::{CpuLimit, CpuRates, percpu_samples},
+        cpuset::CpuSet,
+        files::CgroupDir,
+        hierarchy::{CgroupEvents, CgroupStat},
+        hugetlb::hugetlb_samples,
+        interface_files::interface_file_samples,
+        io::{io_limit_samples, io_stat_samples},
+        lifecycle::{created_timestamp, freezer_samples},
+        memory::{
+            MemoryEvents, MemoryLimit, MemoryStatV2, SwapEvents, SwapUsage, numa_stat_samples,
+        },
+        network::network_samples,
+        pids::Pids,
+        pressure::Pressure,
+    },
+    matcher::{CgroupMatcher, MatchableInterfaceFile, NameMatcher},
     procs::{Proc, ProcessMetrics},
-    render::Named,
+    render::{LabeledSample, Named},
     shell::Evaluator,
 };
 
@@ -27,12 +46,31 @@ use cgroups_exporter_config::{RewriteCgroupName, Templated};
 pub struct CgroupMetrics {
     #[serde(skip)]
     pub name: String,
+    #[serde(skip)]
+    pub labeled_samples: Vec<LabeledSample>,
 
     pub cpu: Option<CpuStat>,
+    pub cpu_limit: Option<CpuLimit>,
+    pub cpu_throttled_ratio: Option<f64>,
+    pub cpu_limit_utilization: Option<f64>,
     pub cpuacct: Option<CpuAcct>,
     pub cpuset: Option<CpuSet>,
     pub memory: Option<Memory>,
     pub blkio: Option<BlkIo>,
+    pub pressure: Option<Pressure>,
+    pub pids: Option<Pids>,
+    pub stat: Option<CgroupStat>,
+    pub events: Option<CgroupEvents>,
+    pub memory_stat_v2: Option<MemoryStatV2>,
+    pub memory_limit: Option<MemoryLimit>,
+    pub memory_peak: Option<u64>,
+    pub memory_events: Option<MemoryEvents>,
+    pub memory_events_local: Option<MemoryEvents>,
+    pub memory_swap_events: Option<SwapEvents>,
+    pub memory_swap: Option<SwapUsage>,
+    pub memory_zswap: Option<SwapUsage>,
+    pub memory_working_set_bytes: Option<u64>,
+    pub created_timestamp_seconds: Option<f64>,
 
     // It would be easier to reuse the `ProcessMetrics struct and use `#[serde(flatten)]`,
     // but the serde uses a the map serializer and serde_prom doesn't support maps yet.
@@ -42,12 +80,26 @@ pub struct CgroupMetrics {
     pub cpu_seconds_total: f64,
     pub memory_usage_bytes: u64,
     pub num_fds: u64,
+    pub fd_soft_limit: Option<u64>,
+    pub fd_hard_limit: Option<u64>,
+    pub max_fd_utilization_ratio: Option<f64>,
     pub num_procs: u64,
     pub num_threads: u64,
     pub io_read_bytes_total: u64,
     pub io_write_bytes_total: u64,
     pub major_page_faults_total: u64,
     pub minor_page_faults_total: u64,
+    pub voluntary_context_switches_total: u64,
+    pub nonvoluntary_context_switches_total: u64,
+    pub schedstat_running_seconds_total: f64,
+    pub schedstat_waiting_seconds_total: f64,
+    pub swap_bytes: u64,
+    pub rss_peak_bytes: u64,
+    pub virtual_memory_peak_bytes: u64,
+    pub locked_memory_bytes: u64,
+    pub pss_bytes: Option<u64>,
+    pub uss_bytes: Option<u64>,
+    pub shared_memory_bytes: Option<u64>,
     pub start_time: Option<i64>,
 }
 
@@ -55,6 +107,8 @@ impl CgroupMetrics {
     pub fn from_cgroup_blocking<E>(
         cgroup: &Cgroup,
         matcher: &CgroupMatcher,
+        files: &[MatchableInterfaceFile],
+        smaps_rollup: bool,
         evaluator: &E,
     ) -> anyhow::Result<Self>
     where
@@ -66,38 +120,141 @@ impl CgroupMetrics {
         };
 
         if let Some(ctrl) = cgroup.controller_of::<MemController>() {
-            metrics.memory = Some(ctrl.memory_stat());
+            let memory = ctrl.memory_stat();
+            if !cgroup.v2() {
+                // Like cAdvisor, the hierarchical `total_inactive_file` is subtracted on v1.
+                metrics.memory_working_set_bytes = Some(
+                    memory
+                        .usage_in_bytes
+                        .saturating_sub(memory.stat.total_inactive_file),
+                );
+            }
+            metrics.memory = Some(memory);
+            metrics.labeled_samples.extend(numa_stat_samples(cgroup));
         }
 
         if cgroup.v2() {
-            if let Some(ctrl) = cgroup.controller_of::<CpuController>() {
-                metrics.cpu = Some(parse_v2_stat(&ctrl.cpu().stat));
-            }
+            metrics.set_v2_metrics(cgroup);
+        } else if let Some(ctrl) = cgroup.controller_of::<BlkIoController>() {
+            let blkio = ctrl.blkio();
+            metrics.labeled_samples.extend(blkio_samples(&blkio));
+            metrics.blkio = Some((&blkio).into());
+        }
+
+        if cgroup.controller_of::<CpuController>().is_some() {
+            metrics.cpu_limit = Some(CpuLimit::read(cgroup));
+        }
+
+        if cgroup.controller_of::<CpuSetController>().is_some() {
+            let cpuset = CpuSet::read(cgroup);
+            metrics.labeled_samples.extend(cpuset.info());
+            metrics.cpuset = Some(cpuset);
         }
 
         if let Some(ctrl) = cgroup.controller_of::<CpuAcctController>() {
-            metrics.cpuacct = Some(ctrl.cpuacct());
+            let cpuacct = ctrl.cpuacct();
+            metrics.labeled_samples.extend(percpu_samples(&cpuacct));
+            metrics.cpuacct = Some(cpuacct);
         }
+        metrics.set_cpu_rates(cgroup);
 
-        if let Some(ctrl) = cgroup.controller_of::<BlkIoController>() {
-            metrics.blkio = Some(ctrl.blkio());
+        if cgroup.controller_of::<PidController>().is_some() {
+            metrics.pids = Some(Pids::read(cgroup));
         }
 
-        let processes_iter = cgroup.procs().into_iter().filter_map(|pid| {
-            let pid = pid.pid.saturating_cast();
-            Process::new(pid).ok()
-        });
+        if let Some(ctrl) = cgroup.controller_of::<HugeTlbController>() {
+            metrics
+                .labeled_samples
+                .extend(hugetlb_samples(cgroup, &ctrl.get_sizes()));
+        }
+
+        metrics.created_timestamp_seconds = created_timestamp(cgroup);
+        metrics.labeled_samples.extend(freezer_samples(cgroup));
+
+        metrics
+            .labeled_samples
+            .extend(interface_file_samples(cgroup, files));
+
+        let processes: Vec<Process> = cgroup
+            .procs()
+            .into_iter()
+            .filter_map(|pid| {
+                let pid = pid.pid.saturating_cast();
+                Process::new(pid).ok()
+            })
+            .collect();
 
-        metrics.set_proc_metrics(processes_iter);
+        metrics.labeled_samples.extend(network_samples(&processes));
+        metrics.set_proc_metrics(processes.into_iter(), smaps_rollup);
 
         Ok(metrics)
     }
 
+    /// Reads the interface files that only exist on v2.
+    fn set_v2_metrics(&mut self, cgroup: &Cgroup) {
+        let dir = CgroupDir::unified(cgroup);
+        self.pressure = Some(Pressure::read(&dir));
+        self.stat = Some(CgroupStat::read(&dir));
+        self.events = Some(CgroupEvents::read(&dir));
+        if let Some(ctrl) = cgroup.controller_of::<CpuController>() {
+            self.cpu = Some(parse_v2_stat(&ctrl.cpu().stat));
+        }
+        if cgroup.controller_of::<MemController>().is_some() {
+            self.memory_stat_v2 = MemoryStatV2::read(&dir);
+            self.memory_limit = Some(MemoryLimit::read(&dir));
+            self.memory_peak = dir.read_value("memory.peak");
+            self.memory_events = MemoryEvents::read(&dir, "memory.events");
+            self.memory_events_local = MemoryEvents::read(&dir, "memory.events.local");
+            self.memory_swap_events = SwapEvents::read(&dir);
+            self.memory_swap = Some(SwapUsage::read_swap(&dir));
+            self.memory_zswap = Some(SwapUsage::read_zswap(&dir));
+            let usage: Option<u64> = dir.read_value("memory.current");
+            let inactive_file = self
+                .memory_stat_v2
+                .as_ref()
+                .and_then(|stat| stat.inactive_file);
+            self.memory_working_set_bytes =
+                usage.map(|usage| usage.saturating_sub(inactive_file.unwrap_or_default()));
+        }
+        // The v2 io controller has no totals, so `io.stat` is only exported per device.
+        if cgroup.controller_of::<BlkIoController>().is_some() {
+            self.labeled_samples.extend(io_stat_samples(&dir));
+            self.labeled_samples.extend(io_limit_samples(&dir));
+        }
+    }
+
+    /// Computes the CPU rates from the `cpu.stat` and CPU usage values that were already read.
+    fn set_cpu_rates(&mut self, cgroup: &Cgroup) {
+        let Some(limit) = &self.cpu_limit else {
+            return;
+        };
+        let rates = if cgroup.v2() {
+            let Some(stat) = &self.cpu else {
+                return;
+            };
+            CpuRates::compute(cgroup, limit, stat, stat.usage_usec)
+        } else {
+            // The v1 `cpu.stat` is not exported, but it has the same period counters as on v2.
+            let Some(ctrl) = cgroup.controller_of::<CpuController>() else {
+                return;
+            };
+            let stat = parse_v2_stat(&ctrl.cpu().stat);
+            // `cpuacct.usage` is in nanoseconds.
+            let usage_usec = self.cpuacct.as_ref().map(|cpuacct| cpuacct.usage / 1000);
+            CpuRates::compute(cgroup, limit, &stat, usage_usec)
+        };
+        self.cpu_throttled_ratio = rates.throttled_ratio;
+        self.cpu_limit_utilization = rates.limit_utilization;
+    }
+
     #[allow(clippy::similar_names)]
-    fn set_proc_metrics(&mut self, procs: impl Iterator<Item = Process>) {
+    fn set_proc_metrics(&mut self, procs: impl Iterator<Item = Process>, smaps_rollup: bool) {
         let procs_iter = procs.filter_map(|proc| {
             let mut proc: Proc = proc.try_into().ok()?;
             proc.gather_remaining_info().ok()?;
+            if smaps_rollup {
+                proc.gather_smaps_rollup();
+            }
             Some(proc)
         });
         let metrics = ProcessMetrics::from_processes(procs_iter, "");
@@ -108,13 +265,28 @@ impl CgroupMetrics {
         self.stime = metrics.stime;
         self.cpu_seconds_total = metrics.cpu_seconds_total;
         self.num_fds = metrics.num_fds;
+        self.fd_soft_limit = metrics.fd_soft_limit;
+        self.fd_hard_limit = metrics.fd_hard_limit;
+        self.max_fd_utilization_ratio = metrics.max_fd_utilization_ratio;
         self.num_procs = metrics.num_procs;
         self.num_threads = metrics.num_threads;
         self.io_read_bytes_total = metrics.io_read_bytes_total;
         self.io_write_bytes_total = metrics.io_write_bytes_total;
         self.major_page_faults_total = metrics.major_page_faults_total;
         self.minor_page_faults_total = metrics.minor_page_faults_total;
+        self.voluntary_context_switches_total = metrics.voluntary_context_switches_total;
+        self.nonvoluntary_context_switches_total = metrics.nonvoluntary_context_switches_total;
+        self.schedstat_running_seconds_total = metrics.schedstat_running_seconds_total;
+        self.schedstat_waiting_seconds_total = metrics.schedstat_waiting_seconds_total;
+        self.swap_bytes = metrics.swap_bytes;
+        self.rss_peak_bytes = metrics.rss_peak_bytes;
+        self.virtual_memory_peak_bytes = metrics.virtual_memory_peak_bytes;
+        self.locked_memory_bytes = metrics.locked_memory_bytes;
+        self.pss_bytes = metrics.pss_bytes;
+        self.uss_bytes = metrics.uss_bytes;
+        self.shared_memory_bytes = metrics.shared_memory_bytes;
         self.start_time = metrics.start_time;
+        self.labeled_samples.extend(metrics.labeled_samples);
     }
 
     fn rewrite_name<E>(
@@ -179,6 +351,10 @@ impl Named for CgroupMetrics {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn labeled_samples(&self) -> &[LabeledSample] {
+        &self.labeled_samples
+    }
 }
 
 #[derive(Serialize, Default)]
@@ -247,7 +423,8 @@ mod tests {
             .include(vec![filter.into()])
             .build()?;
         for cgroup in explorer.iter_cgroups() {
-            let metrics = CgroupMetrics::from_cgroup_blocking(&cgroup, &matcher, &evaluator)?;
+            let metrics =
+                CgroupMetrics::from_cgroup_blocking(&cgroup, &matcher, &[], false, &evaluator)?;
             let mut labels = global_labels.clone();
             labels.insert("cgroup", &metrics.name);
 
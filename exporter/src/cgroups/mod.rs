@@ -1,4 +1,17 @@
+mod blkio;
+mod cpu;
+mod cpuset;
 mod discover;
+mod files;
+mod hierarchy;
+mod hugetlb;
+mod interface_files;
+mod io;
+mod lifecycle;
+mod memory;
 mod metrics;
+mod network;
+mod pids;
+mod pressure;
 
 pub use discover::discover_cgroups_metrics;
@@ -0,0 +1,48 @@
+use std::{collections::HashSet, fs, os::unix::fs::MetadataExt as _};
+
+use procfs::process::Process;
+
+use crate::render::LabeledSample;
+
+/// Reads the network interface statistics of the network namespaces that the processes are in.
+/// Each namespace is read once, through the first process that is in it. The host namespace is
+/// skipped, so that cgroups without their own namespace do not all report the host interfaces.
+pub fn network_samples(processes: &[Process]) -> Vec<LabeledSample> {
+    let host_netns = netns_inode(1);
+    let mut seen = HashSet::new();
+    let mut samples = Vec::new();
+    for process in processes {
+        let Some(netns) = netns_inode(process.pid()) else {
+            continue;
+        };
+        if Some(netns) == host_netns || !seen.insert(netns) {
+            continue;
+        }
+        let Ok(interfaces) = process.dev_status() else {
+            continue;
+        };
+        for (interface, status) in interfaces {
+            let labels = vec![("interface", interface), ("netns", netns.to_string())];
+            for (metric, value) in [
+                ("network_receive_bytes", status.recv_bytes),
+                ("network_receive_packets", status.recv_packets),
+                ("network_receive_errors", status.recv_errs),
+                ("network_receive_drops", status.recv_drop),
+                ("network_transmit_bytes", status.sent_bytes),
+                ("network_transmit_packets", status.sent_packets),
+                ("network_transmit_errors", status.sent_errs),
+                ("network_transmit_drops", status.sent_drop),
+            ] {
+                samples.push(LabeledSample::new(metric, labels.clone(), value));
+            }
+        }
+    }
+    samples
+}
+
+/// The inode of the network namespace of a process, which identifies the namespace.
+fn netns_inode(pid: i32) -> Option<u64> {
+    fs::metadata(format!("/proc/{pid}/ns/net"))
+        .ok()
+        .map(|metadata| metadata.ino())
+}
@@ -0,0 +1,30 @@
+use cgroups_rs::Cgroup;
+use serde::Serialize;
+
+use crate::cgroups::files::CgroupDir;
+
+/// Metrics of the pids controller, for both v1 and v2.
+#[derive(Serialize, Default)]
+pub struct Pids {
+    pub current: Option<u64>,
+    /// `None` if there is no limit.
+    pub max: Option<u64>,
+    /// Only available on v2, since Linux 6.1.
+    pub peak: Option<u64>,
+    /// How many times a fork failed because the limit was hit.
+    pub events_max: Option<u64>,
+}
+
+impl Pids {
+    pub fn read(cgroup: &Cgroup) -> Self {
+        let Some(dir) = CgroupDir::for_controller(cgroup, "pids") else {
+            return Self::default();
+        };
+        Self {
+            current: dir.read_value("pids.current"),
+            max: dir.read_limit("pids.max"),
+            peak: dir.read_value("pids.peak"),
+            events_max: dir.read_keyed_value("pids.events", "max"),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use serde::Serialize;
+
+use crate::cgroups::files::{CgroupDir, parse_nested_keyed};
+
+/// Pressure stall information from `cpu.pressure`, `memory.pressure` and `io.pressure` (v2 only).
+#[derive(Serialize, Default)]
+pub struct Pressure {
+    pub cpu: Option<PressureResource>,
+    pub memory: Option<PressureResource>,
+    pub io: Option<PressureResource>,
+}
+
+impl Pressure {
+    pub fn read(dir: &CgroupDir) -> Self {
+        Self {
+            cpu: PressureResource::read(dir, "cpu.pressure"),
+            memory: PressureResource::read(dir, "memory.pressure"),
+            io: PressureResource::read(dir, "io.pressure"),
+        }
+    }
+}
+
+#[derive(Serialize, Default, Debug, PartialEq)]
+pub struct PressureResource {
+    /// Some tasks were stalled on the resource.
+    pub some: Option<PressureLine>,
+    /// All non-idle tasks were stalled on the resource at the same time.
+    pub full: Option<PressureLine>,
+}
+
+impl PressureResource {
+    fn read(dir: &CgroupDir, file: &str) -> Option<Self> {
+        dir.read(file).ok().map(|content| parse_pressure(&content))
+    }
+}
+
+#[derive(Serialize, Default, Debug, PartialEq)]
+pub struct PressureLine {
+    pub avg10: f64,
+    pub avg60: f64,
+    pub avg300: f64,
+    pub stall_usec: u64,
+}
+
+fn parse_pressure(content: &str) -> PressureResource {
+    let mut resource = PressureResource::default();
+    for (key, fields) in parse_nested_keyed(content) {
+        let mut line = PressureLine::default();
+        for (field, value) in fields {
+            match field {
+                "avg10" => line.avg10 = value.parse().unwrap_or_default(),
+                "avg60" => line.avg60 = value.parse().unwrap_or_default(),
+                "avg300" => line.avg300 = value.parse().unwrap_or_default(),
+                "total" => line.stall_usec = value.parse().unwrap_or_default(),
+                _ => {}
+            }
+        }
+        match key {
+            "some" => resource.some = Some(line),
+            "full" => resource.full = Some(line),
+            _ => {}
+        }
+    }
+    resource
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_cpu_pressure() {
+        let content = "some avg10=1.50 avg60=0.75 avg300=0.10 total=123456\n\
+                       full avg10=0.00 avg60=0.00 avg300=0.00 total=0\n";
+        assert_eq!(
+            parse_pressure(content),
+            PressureResource {
+                some: Some(PressureLine {
+                    avg10: 1.5,
+                    avg60: 0.75,
+                    avg300: 0.1,
+                    stall_usec: 123_456,
+                }),
+                full: Some(PressureLine::default()),
+            }
+        );
+    }
+}
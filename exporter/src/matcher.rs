@@ -1,16 +1,20 @@
 use std::fmt;
 
 use cgroups_exporter_config::{
-    CgroupConfig, CgroupMatch, Config, MetricsConfig, NameMatch, ProcessConfig, ProcessMatch,
-    RewriteCgroupName, ShellCommandsConfig, Templated,
+    CgroupConfig, CgroupMatch, Config, InterfaceFile, InterfaceFileFormat, InterfaceFileMetricType,
+    MetricsConfig, NameMatch, ProcessConfig, ProcessMatch, RewriteCgroupName, ShellCommandsConfig,
+    Templated,
 };
 use regex::Regex;
 
+use crate::metadata::{Metadata, intern, is_builtin};
+
 #[derive(Debug, Clone)]
 pub struct MatchableConfig {
     pub cgroups: Vec<MatchableCgroupConfig>,
     pub processes: Vec<MatchableProcessConfig>,
     pub shell_commands: ShellCommandsConfig,
+    pub metadata: Metadata,
 }
 
 /// A mirror of `CgroupConfig` but with parsed Regex and no serialization.
@@ -18,6 +22,18 @@ pub struct MatchableConfig {
 pub struct MatchableCgroupConfig {
     pub match_by: CgroupMatcher,
     pub metrics: MetricsConfig,
+    pub files: Vec<MatchableInterfaceFile>,
+}
+
+/// A mirror of `InterfaceFile` but with the metric name and help text resolved.
+#[derive(Debug, Clone)]
+pub struct MatchableInterfaceFile {
+    pub file: String,
+    pub format: InterfaceFileFormat,
+    pub metric_type: InterfaceFileMetricType,
+    pub metric: &'static str,
+    pub rename: Option<&'static str>,
+    pub help: &'static str,
 }
 
 /// A mirror of `ProcessConfig` but with parsed Regex and no serialization.
@@ -110,13 +126,70 @@ impl TryFrom<ProcessMatch> for ProcessMatcher {
     }
 }
 
+impl TryFrom<InterfaceFile> for MatchableInterfaceFile {
+    type Error = anyhow::Error;
+
+    fn try_from(value: InterfaceFile) -> Result<Self, Self::Error> {
+        // The file is joined to the cgroup directory as is, so it must not point anywhere else.
+        if value.file.is_empty()
+            || value.file.contains('/')
+            || value.file == "."
+            || value.file == ".."
+        {
+            anyhow::bail!("Invalid cgroup interface file name: {:?}", value.file);
+        }
+        let metric = value
+            .file
+            .replace(|c: char| !c.is_ascii_alphanumeric() && c != '_', "_");
+        let rename = match value.metric_type {
+            InterfaceFileMetricType::Gauge => None,
+            InterfaceFileMetricType::Counter => Some(intern(&format!("{metric}_total"))),
+        };
+        if is_builtin(&metric) || rename.is_some_and(is_builtin) {
+            anyhow::bail!(
+                "Cgroup interface file {} clashes with the built-in metric {metric}",
+                value.file
+            );
+        }
+        let help = match &value.help {
+            Some(help) => intern(help),
+            None => intern(&format!(
+                "Read from the cgroup interface file {}",
+                value.file
+            )),
+        };
+        Ok(Self {
+            metric: intern(&metric),
+            rename,
+            help,
+            file: value.file,
+            format: value.format,
+            metric_type: value.metric_type,
+        })
+    }
+}
+
 impl TryFrom<CgroupConfig> for MatchableCgroupConfig {
     type Error = anyhow::Error;
 
     fn try_from(value: CgroupConfig) -> Result<Self, Self::Error> {
+        let mut files: Vec<MatchableInterfaceFile> = Vec::new();
+        for file in value.files {
+            let file: MatchableInterfaceFile = file.try_into()?;
+            if let Some(other) = files.iter().find(|other| other.clashes_with(&file)) {
+                anyhow::bail!(
+                    "Cgroup interface files {} and {} are both exported as {}",
+                    other.file,
+                    file.file,
+                    file.exported_name()
+                );
+            }
+            files.push(file);
+        }
         Ok(Self {
             match_by: value.match_by.try_into()?,
             metrics: value.metrics,
+            files,
         })
     }
 }
@@ -136,7 +209,7 @@ impl TryFrom<Config> for MatchableConfig {
     type Error = anyhow::Error;
 
     fn try_from(value: Config) -> Result<Self, Self::Error> {
-        let mut cgroups = Vec::new();
+        let mut cgroups: Vec<MatchableCgroupConfig> = Vec::new();
         for cgroup in value.cgroups {
             cgroups.push(cgroup.try_into()?);
         }
@@ -144,14 +217,47 @@ impl TryFrom<Config> for MatchableConfig {
         for process in value.processes {
             processes.push(process.try_into()?);
         }
+        // The metadata is keyed by the metric name only, so the same name in two cgroup configs
+        // must describe the same metric.
+        let files: Vec<&MatchableInterfaceFile> =
+            cgroups.iter().flat_map(|cgroup| &cgroup.files).collect();
+        for (i, file) in files.iter().enumerate() {
+            if let Some(other) = files[..i].iter().find(|other| {
+                other.clashes_with(file)
+                    && (other.file != file.file
+                        || other.format != file.format
+                        || other.metric_type != file.metric_type
+                        || other.help != file.help)
+            }) {
+                anyhow::bail!(
+                    "Cgroup interface files {} and {} are exported as {} with different settings",
+                    other.file,
+                    file.file,
+                    file.exported_name()
+                );
+            }
+        }
+        let metadata = Metadata::new(cgroups.iter().flat_map(|cgroup| &cgroup.files));
         Ok(Self {
             cgroups,
             processes,
             shell_commands: value.shell_commands,
+            metadata,
         })
     }
 }
 
+impl MatchableInterfaceFile {
+    /// Returns the name the metric is exported as, without the namespace.
+    fn exported_name(&self) -> &'static str {
+        self.rename.unwrap_or(self.metric)
+    }
+
+    fn clashes_with(&self, other: &Self) -> bool {
+        self.metric == other.metric || self.exported_name() == other.exported_name()
+    }
+}
+
 impl ProcessMatcher {
     /// Returns the group name for this matcher from config.
     #[must_use]
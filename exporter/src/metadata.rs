@@ -1,8 +1,86 @@
-use std::{collections::HashMap, sync::LazyLock};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    ops::Deref,
+    sync::{Arc, LazyLock, Mutex},
+};
 
+use cgroups_exporter_config::InterfaceFileMetricType;
 use serde_prom::{MetricDescriptor, MetricType};
 
-pub static METADATA: LazyLock<HashMap<&str, MetricDescriptor<'_>>> = LazyLock::new(|| {
+use crate::matcher::MatchableInterfaceFile;
+
+/// Metric names and help texts that come from the config file. They must be `'static` to be
+/// used as serde field names, so each distinct string is leaked once and reused across reloads.
+static INTERNED: LazyLock<Mutex<HashSet<&'static str>>> = LazyLock::new(Mutex::default);
+
+pub fn intern(value: &str) -> &'static str {
+    let mut interned = INTERNED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(&existing) = interned.get(value) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(value.to_owned().into_boxed_str());
+    interned.insert(leaked);
+    leaked
+}
+
+static BUILTIN_METADATA: LazyLock<HashMap<&'static str, MetricDescriptor<'static>>> =
+    LazyLock::new(builtin_metadata);
+
+/// The metadata of the built-in metrics, and of the metrics read from the cgroup interface files
+/// in the config. Built once each time the config is loaded.
+#[derive(Clone)]
+pub struct Metadata(Arc<HashMap<&'static str, MetricDescriptor<'static>>>);
+
+impl Metadata {
+    pub fn new<'a>(files: impl Iterator<Item = &'a MatchableInterfaceFile>) -> Self {
+        let mut metadata = BUILTIN_METADATA.clone();
+        for file in files {
+            metadata.insert(
+                file.metric,
+                MetricDescriptor {
+                    metric_type: match file.metric_type {
+                        InterfaceFileMetricType::Gauge => MetricType::Gauge,
+                        InterfaceFileMetricType::Counter => MetricType::Counter,
+                    },
+                    help: file.help,
+                    labels: vec![],
+                    rename: file.rename,
+                },
+            );
+        }
+        Self(Arc::new(metadata))
+    }
+}
+
+impl Deref for Metadata {
+    type Target = HashMap<&'static str, MetricDescriptor<'static>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Metadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Metadata")
+            .field("metrics", &self.0.len())
+            .finish()
+    }
+}
+
+/// Returns whether a built-in metric is named `name`, either by its field name or by the name it
+/// is exported as.
+pub fn is_builtin(name: &str) -> bool {
+    BUILTIN_METADATA
+        .iter()
+        .any(|(&key, descriptor)| key == name || descriptor.rename == Some(name))
+}
+
+#[allow(clippy::too_many_lines)]
+fn builtin_metadata() -> HashMap<&'static str, MetricDescriptor<'static>> {
     HashMap::from([
         // PROCESS METRICS
         (
@@ -122,6 +200,141 @@ pub static METADATA: LazyLock<HashMap<&str, MetricDescriptor<'_>>> = LazyLock::n
                 rename: None,
             },
         ),
+        (
+            "num_procs_by_state",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Number of processes by state",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "voluntary_context_switches_total",
+            MetricDescriptor {
+                metric_type: MetricType::Counter,
+                help: "Number of voluntary context switches",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "nonvoluntary_context_switches_total",
+            MetricDescriptor {
+                metric_type: MetricType::Counter,
+                help: "Number of involuntary context switches",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "swap_bytes",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Swapped out memory in bytes",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "rss_peak_bytes",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Peak Resident Set Size in bytes",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "virtual_memory_peak_bytes",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Peak virtual memory size in bytes",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "locked_memory_bytes",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Locked memory in bytes",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "pss_bytes",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Proportional Set Size in bytes",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "uss_bytes",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Unique Set Size in bytes",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "shared_memory_bytes",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Memory shared with other processes in bytes",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "schedstat_running_seconds_total",
+            MetricDescriptor {
+                metric_type: MetricType::Counter,
+                help: "Time spent running on a CPU in seconds",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "schedstat_waiting_seconds_total",
+            MetricDescriptor {
+                metric_type: MetricType::Counter,
+                help: "Time spent waiting on a run queue in seconds",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "fd_soft_limit",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Lowest soft limit on the number of file descriptors",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "fd_hard_limit",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Lowest hard limit on the number of file descriptors",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "max_fd_utilization_ratio",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Highest ratio of file descriptors to the soft limit of a single process",
+                labels: vec![],
+                rename: None,
+            },
+        ),
         // CGROUP CPU METRICS
         (
             "cpu_usage_usec",
@@ -781,1732 +994,1917 @@ pub static METADATA: LazyLock<HashMap<&str, MetricDescriptor<'_>>> = LazyLock::n
         // CGROUP BLKIO METRICS
         // BlkIo struct fields
 
-        // Field: io_merged: Vec<IoService>
-        // Nested IoService fields prefixed with "blkio_io_merged_"
+        // Field: io_merged_total: u64
         (
-            "blkio_io_merged_major",
+            "blkio_io_merged_total",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The major number of the device.",
+                metric_type: MetricType::Counter,
+                help: "Total number of BIOs merged into requests for I/O operations on all devices.",
                 labels: vec![],
                 rename: None,
             },
         ),
+        // Field: io_merged_recursive_total: u64
         (
-            "blkio_io_merged_minor",
+            "blkio_io_merged_recursive_total",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The minor number of the device.",
+                metric_type: MetricType::Counter,
+                help: "Total number of BIOs merged into requests on all devices, including descendant control groups.",
                 labels: vec![],
                 rename: None,
             },
         ),
+        // Field: io_queued_total: u64
         (
-            "blkio_io_merged_read",
+            "blkio_io_queued_total",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were read from the device.",
+                metric_type: MetricType::Gauge,
+                help: "Total number of requests queued for I/O operations on all devices.",
                 labels: vec![],
-                rename: Some("blkio_io_merged_read_total"),
+                rename: None,
             },
         ),
+        // Field: io_queued_recursive_total: u64
         (
-            "blkio_io_merged_write",
+            "blkio_io_queued_recursive_total",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were written to the device.",
+                metric_type: MetricType::Gauge,
+                help: "Total number of requests queued for I/O on all devices, including descendant control groups.",
                 labels: vec![],
-                rename: Some("blkio_io_merged_write_total"),
+                rename: None,
             },
         ),
+        // Field: io_service_bytes_total: u64
         (
-            "blkio_io_merged_sync",
+            "blkio_io_service_bytes_total",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were synchronously transferred.",
+                help: "Total number of bytes transferred to or from all devices.",
                 labels: vec![],
-                rename: Some("blkio_io_merged_sync_total"),
+                rename: None,
             },
         ),
+        // Field: io_service_bytes_recursive_total: u64
         (
-            "blkio_io_merged_async",
+            "blkio_io_service_bytes_recursive_total",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were asynchronously transferred.",
+                help: "Total number of bytes transferred to or from all devices, including descendant control groups.",
                 labels: vec![],
-                rename: Some("blkio_io_merged_async_total"),
+                rename: None,
             },
         ),
+        // Field: io_serviced_total: u64
         (
-            "blkio_io_merged_discard",
+            "blkio_io_serviced_total",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were discarded.",
+                help: "Total number of I/O operations issued to all devices.",
                 labels: vec![],
-                rename: Some("blkio_io_merged_discard_total"),
+                rename: None,
             },
         ),
+        // Field: io_serviced_recursive_total: u64
         (
-            "blkio_io_merged_total",
+            "blkio_io_serviced_recursive_total",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Total number of items transferred.",
+                help: "Total number of I/O operations issued to all devices, including descendant control groups.",
                 labels: vec![],
-                rename: Some("blkio_io_merged_total"),
+                rename: None,
             },
         ),
-        // Field: io_merged_total: u64
+        // Field: io_service_time_total: u64
         (
-            "blkio_io_merged_total",
+            "blkio_io_service_time_total",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Same as `io_merged`, but only reports the total number.",
-                labels: vec![],
-                rename: Some("blkio_io_merged_total"),
-            },
-        ),
-        // Field: io_merged_recursive: Vec<IoService>
-        // Nested IoService fields prefixed with "blkio_io_merged_recursive_"
-        (
-            "blkio_io_merged_recursive_major",
-            MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The major number of the device.",
+                help: "Total time between dispatch and completion of I/O requests on all devices, in nanoseconds.",
                 labels: vec![],
                 rename: None,
             },
         ),
+        // Field: io_service_time_recursive_total: u64
         (
-            "blkio_io_merged_recursive_minor",
+            "blkio_io_service_time_recursive_total",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The minor number of the device.",
+                metric_type: MetricType::Counter,
+                help: "Total I/O service time on all devices in nanoseconds, including descendant control groups.",
                 labels: vec![],
                 rename: None,
             },
         ),
+        // Field: io_wait_time_total: u64
         (
-            "blkio_io_merged_recursive_read",
+            "blkio_io_wait_time_total",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were read from the device.",
+                help: "Total time I/O operations on all devices spent waiting in the scheduler queues, in nanoseconds.",
                 labels: vec![],
-                rename: Some("blkio_io_merged_recursive_read_total"),
+                rename: None,
             },
         ),
+        // Field: io_wait_time_recursive_total: u64
         (
-            "blkio_io_merged_recursive_write",
+            "blkio_io_wait_time_recursive_total",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were written to the device.",
+                help: "Total I/O wait time on all devices in nanoseconds, including descendant control groups.",
                 labels: vec![],
-                rename: Some("blkio_io_merged_recursive_write_total"),
+                rename: None,
             },
         ),
+        // Field: leaf_weight: u64
         (
-            "blkio_io_merged_recursive_sync",
+            "blkio_leaf_weight",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were synchronously transferred.",
+                metric_type: MetricType::Gauge,
+                help: "How much weight the control group's tasks have when competing against descendant control groups.",
                 labels: vec![],
-                rename: Some("blkio_io_merged_recursive_sync_total"),
+                rename: None,
             },
         ),
+        // Field: throttle: BlkIoThrottle
+        // Nested BlkIoThrottle fields prefixed with "blkio_throttle_"
+        // Field: throttle.io_service_bytes_total: u64
         (
-            "blkio_io_merged_recursive_async",
+            "blkio_throttle_io_service_bytes_total",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were asynchronously transferred.",
+                help: "Total number of bytes transferred to or from all devices as seen by the throttling policy.",
                 labels: vec![],
-                rename: Some("blkio_io_merged_recursive_async_total"),
+                rename: None,
             },
         ),
+        // Field: throttle.io_service_bytes_recursive_total: u64
         (
-            "blkio_io_merged_recursive_discard",
+            "blkio_throttle_io_service_bytes_recursive_total",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were discarded.",
+                help: "Total bytes transferred as seen by the throttling policy, including descendant control groups.",
                 labels: vec![],
-                rename: Some("blkio_io_merged_recursive_discard_total"),
+                rename: None,
             },
         ),
+        // Field: throttle.io_serviced_total: u64
         (
-            "blkio_io_merged_recursive_total",
+            "blkio_throttle_io_serviced_total",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Total number of items transferred.",
+                help: "Total number of I/O operations issued to all devices as seen by the throttling policy.",
                 labels: vec![],
-                rename: Some("blkio_io_merged_recursive_total"),
+                rename: None,
             },
         ),
-        // Field: io_merged_recursive_total: u64
+        // Field: throttle.io_serviced_recursive_total: u64
         (
-            "blkio_io_merged_recursive_total",
+            "blkio_throttle_io_serviced_recursive_total",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Same as `io_merged_recursive`, but only reports the total number.",
+                help: "Total I/O operations as seen by the throttling policy, including descendant control groups.",
                 labels: vec![],
-                rename: Some("blkio_io_merged_recursive_total"),
+                rename: None,
             },
         ),
-        // Field: io_queued: Vec<IoService>
-        // Nested IoService fields prefixed with "blkio_io_queued_"
+        // End of throttle fields
+        // Field: weight: u64
         (
-            "blkio_io_queued_major",
+            "blkio_weight",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The major number of the device.",
+                help: "The weight of this control group.",
                 labels: vec![],
                 rename: None,
             },
         ),
+        // CGROUP PRESSURE STALL INFORMATION (cgroup v2 only)
         (
-            "blkio_io_queued_minor",
+            "pressure_cpu_some_avg10",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The minor number of the device.",
+                help: "Percentage of time in the last 10 seconds in which at least some tasks were stalled on CPU.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_io_queued_read",
+            "pressure_cpu_some_avg60",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were read from the device.",
+                metric_type: MetricType::Gauge,
+                help: "Percentage of time in the last 60 seconds in which at least some tasks were stalled on CPU.",
                 labels: vec![],
-                rename: Some("blkio_io_queued_read_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_io_queued_write",
+            "pressure_cpu_some_avg300",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were written to the device.",
+                metric_type: MetricType::Gauge,
+                help: "Percentage of time in the last 300 seconds in which at least some tasks were stalled on CPU.",
                 labels: vec![],
-                rename: Some("blkio_io_queued_write_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_io_queued_sync",
+            "pressure_cpu_some_stall_usec",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were synchronously transferred.",
+                help: "Total time in microseconds in which at least some tasks were stalled on CPU.",
                 labels: vec![],
-                rename: Some("blkio_io_queued_sync_total"),
+                rename: Some("pressure_cpu_some_stall_usec_total"),
             },
         ),
         (
-            "blkio_io_queued_async",
+            "pressure_cpu_full_avg10",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were asynchronously transferred.",
+                metric_type: MetricType::Gauge,
+                help: "Percentage of time in the last 10 seconds in which all non-idle tasks were stalled on CPU.",
                 labels: vec![],
-                rename: Some("blkio_io_queued_async_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_io_queued_discard",
+            "pressure_cpu_full_avg60",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were discarded.",
+                metric_type: MetricType::Gauge,
+                help: "Percentage of time in the last 60 seconds in which all non-idle tasks were stalled on CPU.",
                 labels: vec![],
-                rename: Some("blkio_io_queued_discard_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_io_queued_total",
+            "pressure_cpu_full_avg300",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Total number of items transferred.",
+                metric_type: MetricType::Gauge,
+                help: "Percentage of time in the last 300 seconds in which all non-idle tasks were stalled on CPU.",
                 labels: vec![],
-                rename: Some("blkio_io_queued_total"),
+                rename: None,
             },
         ),
-        // Field: io_queued_total: u64
         (
-            "blkio_io_queued_total",
+            "pressure_cpu_full_stall_usec",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Same as `io_queued`, but only reports the total number.",
+                help: "Total time in microseconds in which all non-idle tasks were stalled on CPU.",
                 labels: vec![],
-                rename: Some("blkio_io_queued_total"),
+                rename: Some("pressure_cpu_full_stall_usec_total"),
             },
         ),
-        // Field: io_queued_recursive: Vec<IoService>
-        // Nested IoService fields prefixed with "blkio_io_queued_recursive_"
         (
-            "blkio_io_queued_recursive_major",
+            "pressure_memory_some_avg10",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The major number of the device.",
+                help: "Percentage of time in the last 10 seconds in which at least some tasks were stalled on memory.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_io_queued_recursive_minor",
+            "pressure_memory_some_avg60",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The minor number of the device.",
+                help: "Percentage of time in the last 60 seconds in which at least some tasks were stalled on memory.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_io_queued_recursive_read",
+            "pressure_memory_some_avg300",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were read from the device.",
+                metric_type: MetricType::Gauge,
+                help: "Percentage of time in the last 300 seconds in which at least some tasks were stalled on memory.",
                 labels: vec![],
-                rename: Some("blkio_io_queued_recursive_read_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_io_queued_recursive_write",
+            "pressure_memory_some_stall_usec",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were written to the device.",
+                help: "Total time in microseconds in which at least some tasks were stalled on memory.",
                 labels: vec![],
-                rename: Some("blkio_io_queued_recursive_write_total"),
+                rename: Some("pressure_memory_some_stall_usec_total"),
             },
         ),
         (
-            "blkio_io_queued_recursive_sync",
+            "pressure_memory_full_avg10",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were synchronously transferred.",
+                metric_type: MetricType::Gauge,
+                help: "Percentage of time in the last 10 seconds in which all non-idle tasks were stalled on memory.",
                 labels: vec![],
-                rename: Some("blkio_io_queued_recursive_sync_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_io_queued_recursive_async",
+            "pressure_memory_full_avg60",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were asynchronously transferred.",
+                metric_type: MetricType::Gauge,
+                help: "Percentage of time in the last 60 seconds in which all non-idle tasks were stalled on memory.",
                 labels: vec![],
-                rename: Some("blkio_io_queued_recursive_async_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_io_queued_recursive_discard",
+            "pressure_memory_full_avg300",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were discarded.",
+                metric_type: MetricType::Gauge,
+                help: "Percentage of time in the last 300 seconds in which all non-idle tasks were stalled on memory.",
                 labels: vec![],
-                rename: Some("blkio_io_queued_recursive_discard_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_io_queued_recursive_total",
+            "pressure_memory_full_stall_usec",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Total number of items transferred.",
+                help: "Total time in microseconds in which all non-idle tasks were stalled on memory.",
                 labels: vec![],
-                rename: Some("blkio_io_queued_recursive_total"),
+                rename: Some("pressure_memory_full_stall_usec_total"),
             },
         ),
-        // Field: io_queued_recursive_total: u64
         (
-            "blkio_io_queued_recursive_total",
+            "pressure_io_some_avg10",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Same as `io_queued_recursive`, but only reports the total number.",
+                metric_type: MetricType::Gauge,
+                help: "Percentage of time in the last 10 seconds in which at least some tasks were stalled on I/O.",
                 labels: vec![],
-                rename: Some("blkio_io_queued_recursive_total"),
+                rename: None,
             },
         ),
-        // Field: io_service_bytes: Vec<IoService>
-        // Nested IoService fields prefixed with "blkio_io_service_bytes_"
         (
-            "blkio_io_service_bytes_major",
+            "pressure_io_some_avg60",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The major number of the device.",
+                help: "Percentage of time in the last 60 seconds in which at least some tasks were stalled on I/O.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_io_service_bytes_minor",
+            "pressure_io_some_avg300",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The minor number of the device.",
+                help: "Percentage of time in the last 300 seconds in which at least some tasks were stalled on I/O.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_io_service_bytes_read",
+            "pressure_io_some_stall_usec",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were read from the device.",
+                help: "Total time in microseconds in which at least some tasks were stalled on I/O.",
                 labels: vec![],
-                rename: Some("blkio_io_service_bytes_read_total"),
+                rename: Some("pressure_io_some_stall_usec_total"),
             },
         ),
         (
-            "blkio_io_service_bytes_write",
+            "pressure_io_full_avg10",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were written to the device.",
+                metric_type: MetricType::Gauge,
+                help: "Percentage of time in the last 10 seconds in which all non-idle tasks were stalled on I/O.",
                 labels: vec![],
-                rename: Some("blkio_io_service_bytes_write_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_io_service_bytes_sync",
+            "pressure_io_full_avg60",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were synchronously transferred.",
+                metric_type: MetricType::Gauge,
+                help: "Percentage of time in the last 60 seconds in which all non-idle tasks were stalled on I/O.",
                 labels: vec![],
-                rename: Some("blkio_io_service_bytes_sync_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_io_service_bytes_async",
+            "pressure_io_full_avg300",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were asynchronously transferred.",
+                metric_type: MetricType::Gauge,
+                help: "Percentage of time in the last 300 seconds in which all non-idle tasks were stalled on I/O.",
                 labels: vec![],
-                rename: Some("blkio_io_service_bytes_async_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_io_service_bytes_discard",
+            "pressure_io_full_stall_usec",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were discarded.",
+                help: "Total time in microseconds in which all non-idle tasks were stalled on I/O.",
                 labels: vec![],
-                rename: Some("blkio_io_service_bytes_discard_total"),
+                rename: Some("pressure_io_full_stall_usec_total"),
             },
         ),
+        // CGROUP IO METRICS (cgroup v2 only, labelled by device)
         (
-            "blkio_io_service_bytes_total",
+            "io_stat_rbytes",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Total number of items transferred.",
+                help: "Number of bytes read from the device.",
                 labels: vec![],
-                rename: Some("blkio_io_service_bytes_total"),
+                rename: Some("io_stat_rbytes_total"),
             },
         ),
-        // Field: io_service_bytes_total: u64
         (
-            "blkio_io_service_bytes_total",
+            "io_stat_wbytes",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Same as `io_service_bytes`, but only reports the total number.",
+                help: "Number of bytes written to the device.",
                 labels: vec![],
-                rename: Some("blkio_io_service_bytes_total"),
+                rename: Some("io_stat_wbytes_total"),
             },
         ),
-        // Field: io_service_bytes_recursive: Vec<IoService>
-        // Nested IoService fields prefixed with "blkio_io_service_bytes_recursive_"
         (
-            "blkio_io_service_bytes_recursive_major",
+            "io_stat_rios",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The major number of the device.",
+                metric_type: MetricType::Counter,
+                help: "Number of read operations issued to the device.",
                 labels: vec![],
-                rename: None,
+                rename: Some("io_stat_rios_total"),
             },
         ),
         (
-            "blkio_io_service_bytes_recursive_minor",
+            "io_stat_wios",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The minor number of the device.",
+                metric_type: MetricType::Counter,
+                help: "Number of write operations issued to the device.",
                 labels: vec![],
-                rename: None,
+                rename: Some("io_stat_wios_total"),
             },
         ),
         (
-            "blkio_io_service_bytes_recursive_read",
+            "io_stat_dbytes",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were read from the device.",
+                help: "Number of bytes discarded on the device.",
                 labels: vec![],
-                rename: Some("blkio_io_service_bytes_recursive_read_total"),
+                rename: Some("io_stat_dbytes_total"),
             },
         ),
         (
-            "blkio_io_service_bytes_recursive_write",
+            "io_stat_dios",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were written to the device.",
+                help: "Number of discard operations issued to the device.",
                 labels: vec![],
-                rename: Some("blkio_io_service_bytes_recursive_write_total"),
+                rename: Some("io_stat_dios_total"),
             },
         ),
+        // CGROUP MEMORY EVENTS METRICS (cgroup v2 only)
         (
-            "blkio_io_service_bytes_recursive_sync",
+            "memory_events_low",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were synchronously transferred.",
+                help: "Number of times the cgroup was reclaimed due to high memory pressure even though its usage is under the low boundary.",
                 labels: vec![],
-                rename: Some("blkio_io_service_bytes_recursive_sync_total"),
+                rename: Some("memory_events_low_total"),
             },
         ),
         (
-            "blkio_io_service_bytes_recursive_async",
+            "memory_events_high",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were asynchronously transferred.",
+                help: "Number of times processes of the cgroup were throttled and routed to direct reclaim because the high boundary was exceeded.",
                 labels: vec![],
-                rename: Some("blkio_io_service_bytes_recursive_async_total"),
+                rename: Some("memory_events_high_total"),
             },
         ),
         (
-            "blkio_io_service_bytes_recursive_discard",
+            "memory_events_max",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were discarded.",
+                help: "Number of times the cgroup's memory usage was about to go over the max boundary.",
                 labels: vec![],
-                rename: Some("blkio_io_service_bytes_recursive_discard_total"),
+                rename: Some("memory_events_max_total"),
             },
         ),
         (
-            "blkio_io_service_bytes_recursive_total",
+            "memory_events_oom",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Total number of items transferred.",
+                help: "Number of times the cgroup's memory usage reached the limit and allocation was about to fail.",
                 labels: vec![],
-                rename: Some("blkio_io_service_bytes_recursive_total"),
+                rename: Some("memory_events_oom_total"),
             },
         ),
-        // Field: io_service_bytes_recursive_total: u64
         (
-            "blkio_io_service_bytes_recursive_total",
+            "memory_events_oom_kill",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Total amount of bytes transferred between the tasks and block devices, including descendant control groups.",
+                help: "Number of processes belonging to this cgroup killed by any kind of OOM killer.",
                 labels: vec![],
-                rename: Some("blkio_io_service_bytes_recursive_total"),
+                rename: Some("memory_events_oom_kill_total"),
             },
         ),
-        // Field: io_serviced: Vec<IoService>
-        // Nested IoService fields prefixed with "blkio_io_serviced_"
         (
-            "blkio_io_serviced_major",
+            "memory_events_oom_group_kill",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The major number of the device.",
+                metric_type: MetricType::Counter,
+                help: "Number of times a group OOM has occurred.",
                 labels: vec![],
-                rename: None,
+                rename: Some("memory_events_oom_group_kill_total"),
             },
         ),
         (
-            "blkio_io_serviced_minor",
+            "memory_events_local_low",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The minor number of the device.",
+                metric_type: MetricType::Counter,
+                help: "Same as memory_events_low, but only for this cgroup and not its descendants.",
                 labels: vec![],
-                rename: None,
+                rename: Some("memory_events_local_low_total"),
             },
         ),
         (
-            "blkio_io_serviced_read",
+            "memory_events_local_high",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were read from the device.",
+                help: "Same as memory_events_high, but only for this cgroup and not its descendants.",
                 labels: vec![],
-                rename: Some("blkio_io_serviced_read_total"),
+                rename: Some("memory_events_local_high_total"),
             },
         ),
         (
-            "blkio_io_serviced_write",
+            "memory_events_local_max",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were written to the device.",
+                help: "Same as memory_events_max, but only for this cgroup and not its descendants.",
                 labels: vec![],
-                rename: Some("blkio_io_serviced_write_total"),
+                rename: Some("memory_events_local_max_total"),
             },
         ),
         (
-            "blkio_io_serviced_sync",
+            "memory_events_local_oom",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were synchronously transferred.",
+                help: "Same as memory_events_oom, but only for this cgroup and not its descendants.",
                 labels: vec![],
-                rename: Some("blkio_io_serviced_sync_total"),
+                rename: Some("memory_events_local_oom_total"),
             },
         ),
         (
-            "blkio_io_serviced_async",
+            "memory_events_local_oom_kill",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were asynchronously transferred.",
+                help: "Same as memory_events_oom_kill, but only for this cgroup and not its descendants.",
                 labels: vec![],
-                rename: Some("blkio_io_serviced_async_total"),
+                rename: Some("memory_events_local_oom_kill_total"),
             },
         ),
         (
-            "blkio_io_serviced_discard",
+            "memory_events_local_oom_group_kill",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were discarded.",
+                help: "Same as memory_events_oom_group_kill, but only for this cgroup and not its descendants.",
                 labels: vec![],
-                rename: Some("blkio_io_serviced_discard_total"),
+                rename: Some("memory_events_local_oom_group_kill_total"),
             },
         ),
         (
-            "blkio_io_serviced_total",
+            "memory_swap_events_high",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Total number of items transferred.",
+                help: "Number of times the cgroup's swap usage was over the high threshold.",
                 labels: vec![],
-                rename: Some("blkio_io_serviced_total"),
+                rename: Some("memory_swap_events_high_total"),
             },
         ),
-        // Field: io_serviced_total: u64
         (
-            "blkio_io_serviced_total",
+            "memory_swap_events_max",
+            MetricDescriptor {
+                metric_type: MetricType::Counter,
+                help: "Number of times the cgroup's swap usage was about to go over the max boundary and swap allocation failed.",
+                labels: vec![],
+                rename: Some("memory_swap_events_max_total"),
+            },
+        ),
+        (
+            "memory_swap_events_fail",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "The total number of I/O operations performed on the devices as seen by the throttling policy.",
+                help: "Number of times swap allocation failed either because of running out of swap system-wide or max limit.",
                 labels: vec![],
-                rename: Some("blkio_io_serviced_total"),
+                rename: Some("memory_swap_events_fail_total"),
             },
         ),
-        // Field: io_serviced_recursive: Vec<IoService>
-        // Nested IoService fields prefixed with "blkio_io_serviced_recursive_"
+        // CGROUP MEMORY STAT METRICS (cgroup v2 only)
         (
-            "blkio_io_serviced_recursive_major",
+            "memory_stat_v2_anon",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The major number of the device.",
+                help: "Amount of memory used in anonymous mappings, in bytes.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_io_serviced_recursive_minor",
+            "memory_stat_v2_file",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The minor number of the device.",
+                help: "Amount of memory used to cache filesystem data, including tmpfs and shared memory, in bytes.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_io_serviced_recursive_read",
+            "memory_stat_v2_kernel",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were read from the device.",
+                metric_type: MetricType::Gauge,
+                help: "Amount of total kernel memory, including kernel stacks, page tables, percpu, vmalloc and slab, in bytes.",
                 labels: vec![],
-                rename: Some("blkio_io_serviced_recursive_read_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_io_serviced_recursive_write",
+            "memory_stat_v2_kernel_stack",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were written to the device.",
+                metric_type: MetricType::Gauge,
+                help: "Amount of memory allocated to kernel stacks, in bytes.",
                 labels: vec![],
-                rename: Some("blkio_io_serviced_recursive_write_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_io_serviced_recursive_sync",
+            "memory_stat_v2_pagetables",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were synchronously transferred.",
+                metric_type: MetricType::Gauge,
+                help: "Amount of memory allocated for page tables, in bytes.",
                 labels: vec![],
-                rename: Some("blkio_io_serviced_recursive_sync_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_io_serviced_recursive_async",
+            "memory_stat_v2_sec_pagetables",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were asynchronously transferred.",
+                metric_type: MetricType::Gauge,
+                help: "Amount of memory allocated for secondary page tables, in bytes.",
                 labels: vec![],
-                rename: Some("blkio_io_serviced_recursive_async_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_io_serviced_recursive_discard",
+            "memory_stat_v2_percpu",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "How many items were discarded.",
+                metric_type: MetricType::Gauge,
+                help: "Amount of memory used for storing per-cpu kernel data structures, in bytes.",
                 labels: vec![],
-                rename: Some("blkio_io_serviced_recursive_discard_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_io_serviced_recursive_total",
+            "memory_stat_v2_sock",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Same as `io_serviced`, but contains all descendant control groups and only the total amount.",
+                metric_type: MetricType::Gauge,
+                help: "Amount of memory used in network transmission buffers, in bytes.",
                 labels: vec![],
-                rename: Some("blkio_io_serviced_recursive_total"),
+                rename: None,
             },
         ),
-        // Field: io_serviced_recursive_total: u64
         (
-            "blkio_io_serviced_recursive_total",
+            "memory_stat_v2_vmalloc",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Same as `io_serviced_recursive`, but contains all descendant control groups and only the total amount.",
+                metric_type: MetricType::Gauge,
+                help: "Amount of memory used for vmap backed memory, in bytes.",
                 labels: vec![],
-                rename: Some("blkio_io_serviced_recursive_total"),
+                rename: None,
             },
         ),
-        // Field: io_service_time: Vec<IoService>
-        // Nested IoService fields prefixed with "blkio_io_service_time_"
         (
-            "blkio_io_service_time_major",
+            "memory_stat_v2_shmem",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The major number of the device.",
+                help: "Amount of cached filesystem data that is swap-backed, such as tmpfs and shm segments, in bytes.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_io_service_time_minor",
+            "memory_stat_v2_zswap",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The minor number of the device.",
+                help: "Amount of memory consumed by the zswap compression backend, in bytes.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_io_service_time_read",
+            "memory_stat_v2_zswapped",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Amount of application memory swapped out to zswap, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_file_mapped",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Amount of cached filesystem data mapped with mmap, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_file_dirty",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Amount of cached filesystem data that was modified but not yet written back to disk, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_file_writeback",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Amount of cached filesystem data that was modified and is currently being written back to disk, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_swapcached",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Amount of swap cached in memory, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_anon_thp",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Amount of memory used in anonymous mappings backed by transparent hugepages, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_file_thp",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Amount of cached filesystem data backed by transparent hugepages, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_shmem_thp",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Amount of shm, tmpfs, shared anonymous mmap()s backed by transparent hugepages, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_inactive_anon",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Amount of anonymous and swap-backed memory on the inactive LRU list, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_active_anon",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Amount of anonymous and swap-backed memory on the active LRU list, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_inactive_file",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Amount of file-backed memory on the inactive LRU list, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_active_file",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Amount of file-backed memory on the active LRU list, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_unevictable",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Amount of memory that cannot be reclaimed, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_slab_reclaimable",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Part of slab that might be reclaimed, such as dentries and inodes, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_slab_unreclaimable",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Part of slab that cannot be reclaimed on memory pressure, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_slab",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Amount of memory used for storing in-kernel data structures, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_hugetlb",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Amount of memory used by hugetlb pages, in bytes.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "memory_stat_v2_workingset_refault_anon",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were read from the device.",
+                help: "Number of refaults of previously evicted anonymous pages.",
                 labels: vec![],
-                rename: Some("blkio_io_service_time_read_total"),
+                rename: Some("memory_stat_v2_workingset_refault_anon_total"),
             },
         ),
         (
-            "blkio_io_service_time_write",
+            "memory_stat_v2_workingset_refault_file",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were written to the device.",
+                help: "Number of refaults of previously evicted file pages.",
                 labels: vec![],
-                rename: Some("blkio_io_service_time_write_total"),
+                rename: Some("memory_stat_v2_workingset_refault_file_total"),
             },
         ),
         (
-            "blkio_io_service_time_sync",
+            "memory_stat_v2_workingset_activate_anon",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were synchronously transferred.",
+                help: "Number of refaulted anonymous pages that were immediately activated.",
                 labels: vec![],
-                rename: Some("blkio_io_service_time_sync_total"),
+                rename: Some("memory_stat_v2_workingset_activate_anon_total"),
             },
         ),
         (
-            "blkio_io_service_time_async",
+            "memory_stat_v2_workingset_activate_file",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were asynchronously transferred.",
+                help: "Number of refaulted file pages that were immediately activated.",
                 labels: vec![],
-                rename: Some("blkio_io_service_time_async_total"),
+                rename: Some("memory_stat_v2_workingset_activate_file_total"),
             },
         ),
         (
-            "blkio_io_service_time_discard",
+            "memory_stat_v2_workingset_restore_anon",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were discarded.",
+                help: "Number of restored anonymous pages which have been detected as an active workingset before they got reclaimed.",
                 labels: vec![],
-                rename: Some("blkio_io_service_time_discard_total"),
+                rename: Some("memory_stat_v2_workingset_restore_anon_total"),
             },
         ),
         (
-            "blkio_io_service_time_total",
+            "memory_stat_v2_workingset_restore_file",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Total time spent between dispatch and completion for I/O requests.",
+                help: "Number of restored file pages which have been detected as an active workingset before they got reclaimed.",
                 labels: vec![],
-                rename: Some("blkio_io_service_time_total"),
+                rename: Some("memory_stat_v2_workingset_restore_file_total"),
             },
         ),
-        // Field: io_service_time_total: u64
         (
-            "blkio_io_service_time_total",
+            "memory_stat_v2_workingset_nodereclaim",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Same as `io_service_time`, but reports only the total amount.",
+                help: "Number of times a shadow node has been reclaimed.",
                 labels: vec![],
-                rename: Some("blkio_io_service_time_total"),
+                rename: Some("memory_stat_v2_workingset_nodereclaim_total"),
             },
         ),
-        // Field: io_service_time_recursive: Vec<IoService>
-        // Nested IoService fields prefixed with "blkio_io_service_time_recursive_"
         (
-            "blkio_io_service_time_recursive_major",
+            "memory_stat_v2_pgscan",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The major number of the device.",
+                metric_type: MetricType::Counter,
+                help: "Amount of scanned pages in an inactive LRU list.",
                 labels: vec![],
-                rename: None,
+                rename: Some("memory_stat_v2_pgscan_total"),
             },
         ),
         (
-            "blkio_io_service_time_recursive_minor",
+            "memory_stat_v2_pgscan_kswapd",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The minor number of the device.",
+                metric_type: MetricType::Counter,
+                help: "Amount of scanned pages by kswapd in an inactive LRU list.",
                 labels: vec![],
-                rename: None,
+                rename: Some("memory_stat_v2_pgscan_kswapd_total"),
             },
         ),
         (
-            "blkio_io_service_time_recursive_read",
+            "memory_stat_v2_pgscan_direct",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were read from the device.",
+                help: "Amount of scanned pages directly in an inactive LRU list.",
                 labels: vec![],
-                rename: Some("blkio_io_service_time_recursive_read_total"),
+                rename: Some("memory_stat_v2_pgscan_direct_total"),
             },
         ),
         (
-            "blkio_io_service_time_recursive_write",
+            "memory_stat_v2_pgscan_khugepaged",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were written to the device.",
+                help: "Amount of scanned pages by khugepaged in an inactive LRU list.",
                 labels: vec![],
-                rename: Some("blkio_io_service_time_recursive_write_total"),
+                rename: Some("memory_stat_v2_pgscan_khugepaged_total"),
             },
         ),
         (
-            "blkio_io_service_time_recursive_sync",
+            "memory_stat_v2_pgsteal",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were synchronously transferred.",
+                help: "Amount of reclaimed pages.",
                 labels: vec![],
-                rename: Some("blkio_io_service_time_recursive_sync_total"),
+                rename: Some("memory_stat_v2_pgsteal_total"),
             },
         ),
         (
-            "blkio_io_service_time_recursive_async",
+            "memory_stat_v2_pgsteal_kswapd",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were asynchronously transferred.",
+                help: "Amount of reclaimed pages by kswapd.",
                 labels: vec![],
-                rename: Some("blkio_io_service_time_recursive_async_total"),
+                rename: Some("memory_stat_v2_pgsteal_kswapd_total"),
             },
         ),
         (
-            "blkio_io_service_time_recursive_discard",
+            "memory_stat_v2_pgsteal_direct",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were discarded.",
+                help: "Amount of reclaimed pages directly.",
                 labels: vec![],
-                rename: Some("blkio_io_service_time_recursive_discard_total"),
+                rename: Some("memory_stat_v2_pgsteal_direct_total"),
             },
         ),
         (
-            "blkio_io_service_time_recursive_total",
+            "memory_stat_v2_pgsteal_khugepaged",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Same as `io_service_time_recursive`, but reports only the total amount.",
+                help: "Amount of reclaimed pages by khugepaged.",
                 labels: vec![],
-                rename: Some("blkio_io_service_time_recursive_total"),
+                rename: Some("memory_stat_v2_pgsteal_khugepaged_total"),
             },
         ),
-        // Field: io_service_time_recursive_total: u64
         (
-            "blkio_io_service_time_recursive_total",
+            "memory_stat_v2_pgfault",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Same as `io_service_time_recursive`, but reports only the total amount.",
+                help: "Total number of page faults incurred.",
                 labels: vec![],
-                rename: Some("blkio_io_service_time_recursive_total"),
+                rename: Some("memory_stat_v2_pgfault_total"),
             },
         ),
-        // Field: io_wait_time: Vec<IoService>
-        // Nested IoService fields prefixed with "blkio_io_wait_time_"
         (
-            "blkio_io_wait_time_major",
+            "memory_stat_v2_pgmajfault",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The major number of the device.",
+                metric_type: MetricType::Counter,
+                help: "Number of major page faults incurred.",
                 labels: vec![],
-                rename: None,
+                rename: Some("memory_stat_v2_pgmajfault_total"),
             },
         ),
         (
-            "blkio_io_wait_time_minor",
+            "memory_stat_v2_pgrefill",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The minor number of the device.",
+                metric_type: MetricType::Counter,
+                help: "Amount of scanned pages in an active LRU list.",
                 labels: vec![],
-                rename: None,
+                rename: Some("memory_stat_v2_pgrefill_total"),
             },
         ),
         (
-            "blkio_io_wait_time_read",
+            "memory_stat_v2_pgactivate",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were read from the device.",
+                help: "Amount of pages moved to the active LRU list.",
                 labels: vec![],
-                rename: Some("blkio_io_wait_time_read_total"),
+                rename: Some("memory_stat_v2_pgactivate_total"),
             },
         ),
         (
-            "blkio_io_wait_time_write",
+            "memory_stat_v2_pgdeactivate",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were written to the device.",
+                help: "Amount of pages moved to the inactive LRU list.",
                 labels: vec![],
-                rename: Some("blkio_io_wait_time_write_total"),
+                rename: Some("memory_stat_v2_pgdeactivate_total"),
             },
         ),
         (
-            "blkio_io_wait_time_sync",
+            "memory_stat_v2_pglazyfree",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were synchronously transferred.",
+                help: "Amount of pages postponed to be freed under memory pressure.",
                 labels: vec![],
-                rename: Some("blkio_io_wait_time_sync_total"),
+                rename: Some("memory_stat_v2_pglazyfree_total"),
             },
         ),
         (
-            "blkio_io_wait_time_async",
+            "memory_stat_v2_pglazyfreed",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were asynchronously transferred.",
+                help: "Amount of reclaimed lazyfree pages.",
                 labels: vec![],
-                rename: Some("blkio_io_wait_time_async_total"),
+                rename: Some("memory_stat_v2_pglazyfreed_total"),
             },
         ),
         (
-            "blkio_io_wait_time_discard",
+            "memory_stat_v2_pgdemote_kswapd",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were discarded.",
+                help: "Number of pages demoted by kswapd.",
                 labels: vec![],
-                rename: Some("blkio_io_wait_time_discard_total"),
+                rename: Some("memory_stat_v2_pgdemote_kswapd_total"),
             },
         ),
         (
-            "blkio_io_wait_time_total",
+            "memory_stat_v2_pgdemote_direct",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Total number of items transferred.",
+                help: "Number of pages demoted directly.",
                 labels: vec![],
-                rename: Some("blkio_io_wait_time_total"),
+                rename: Some("memory_stat_v2_pgdemote_direct_total"),
             },
         ),
-        // Field: io_wait_time_total: u64
         (
-            "blkio_io_wait_time_total",
+            "memory_stat_v2_pgdemote_khugepaged",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Same as `io_wait_time`, but only reports the total amount.",
+                help: "Number of pages demoted by khugepaged.",
                 labels: vec![],
-                rename: Some("blkio_io_wait_time_total"),
+                rename: Some("memory_stat_v2_pgdemote_khugepaged_total"),
             },
         ),
-        // Field: io_wait_time_recursive: Vec<IoService)
-        // Nested IoService fields prefixed with "blkio_io_wait_time_recursive_"
         (
-            "blkio_io_wait_time_recursive_major",
+            "memory_stat_v2_zswpin",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The major number of the device.",
+                metric_type: MetricType::Counter,
+                help: "Number of pages moved in to memory from zswap.",
                 labels: vec![],
-                rename: None,
+                rename: Some("memory_stat_v2_zswpin_total"),
             },
         ),
         (
-            "blkio_io_wait_time_recursive_minor",
+            "memory_stat_v2_zswpout",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The minor number of the device.",
+                metric_type: MetricType::Counter,
+                help: "Number of pages moved out of memory to zswap.",
                 labels: vec![],
-                rename: None,
+                rename: Some("memory_stat_v2_zswpout_total"),
             },
         ),
         (
-            "blkio_io_wait_time_recursive_read",
+            "memory_stat_v2_zswpwb",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were read from the device.",
+                help: "Number of pages written from zswap to swap.",
                 labels: vec![],
-                rename: Some("blkio_io_wait_time_recursive_read_total"),
+                rename: Some("memory_stat_v2_zswpwb_total"),
             },
         ),
         (
-            "blkio_io_wait_time_recursive_write",
+            "memory_stat_v2_thp_fault_alloc",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were written to the device.",
+                help: "Number of transparent hugepages which were allocated to satisfy a page fault.",
                 labels: vec![],
-                rename: Some("blkio_io_wait_time_recursive_write_total"),
+                rename: Some("memory_stat_v2_thp_fault_alloc_total"),
             },
         ),
         (
-            "blkio_io_wait_time_recursive_sync",
+            "memory_stat_v2_thp_collapse_alloc",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were synchronously transferred.",
+                help: "Number of transparent hugepages which were allocated to allow collapsing an existing range of pages.",
                 labels: vec![],
-                rename: Some("blkio_io_wait_time_recursive_sync_total"),
+                rename: Some("memory_stat_v2_thp_collapse_alloc_total"),
             },
         ),
         (
-            "blkio_io_wait_time_recursive_async",
+            "memory_stat_v2_thp_swpout",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were asynchronously transferred.",
+                help: "Number of transparent hugepages which were swapped out in one piece without splitting.",
                 labels: vec![],
-                rename: Some("blkio_io_wait_time_recursive_async_total"),
+                rename: Some("memory_stat_v2_thp_swpout_total"),
             },
         ),
         (
-            "blkio_io_wait_time_recursive_discard",
+            "memory_stat_v2_thp_swpout_fallback",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many items were discarded.",
+                help: "Number of transparent hugepages which were split before swapout.",
                 labels: vec![],
-                rename: Some("blkio_io_wait_time_recursive_discard_total"),
+                rename: Some("memory_stat_v2_thp_swpout_fallback_total"),
             },
         ),
         (
-            "blkio_io_wait_time_recursive_total",
+            "memory_stat_v2_numa_pages_migrated",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Same as `io_wait_time_recursive`, but only reports the total amount.",
+                help: "Number of pages migrated by NUMA balancing.",
                 labels: vec![],
-                rename: Some("blkio_io_wait_time_recursive_total"),
+                rename: Some("memory_stat_v2_numa_pages_migrated_total"),
             },
         ),
-        // Field: io_wait_time_recursive_total: u64
         (
-            "blkio_io_wait_time_recursive_total",
+            "memory_stat_v2_numa_pte_updates",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Same as `io_wait_time_recursive`, but only reports the total amount.",
+                help: "Number of pages whose page table entries were modified by NUMA balancing.",
                 labels: vec![],
-                rename: Some("blkio_io_wait_time_recursive_total"),
+                rename: Some("memory_stat_v2_numa_pte_updates_total"),
             },
         ),
-        // Field: leaf_weight: u64
         (
-            "blkio_leaf_weight",
+            "memory_stat_v2_numa_hint_faults",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "How much weight the control group's tasks have when competing against descendant control groups.",
+                metric_type: MetricType::Counter,
+                help: "Number of NUMA hinting faults.",
                 labels: vec![],
-                rename: None,
+                rename: Some("memory_stat_v2_numa_hint_faults_total"),
             },
         ),
-        // Field: leaf_weight_device: Vec<BlkIoData>
-        // Nested BlkIoData fields prefixed with "blkio_leaf_weight_device_"
+        // CGROUP PIDS METRICS
         (
-            "blkio_leaf_weight_device_major",
+            "pids_current",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The major number of the device.",
+                help: "Number of processes currently in the cgroup and its descendants.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_leaf_weight_device_minor",
+            "pids_max",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The minor number of the device.",
+                help: "Maximum number of processes allowed in the cgroup. Not reported if there is no limit.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_leaf_weight_device_data",
+            "pids_peak",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The data associated with the device.",
+                help: "Maximum number of processes observed in the cgroup and its descendants.",
                 labels: vec![],
                 rename: None,
             },
         ),
-        // Field: sectors: Vec<BlkIoData>
-        // Nested BlkIoData fields prefixed with "blkio_sectors_"
         (
-            "blkio_sectors_major",
+            "pids_events_max",
+            MetricDescriptor {
+                metric_type: MetricType::Counter,
+                help: "Number of times a fork or clone failed because the cgroup hit its pids limit.",
+                labels: vec![],
+                rename: Some("pids_events_max_total"),
+            },
+        ),
+        // CGROUP HUGETLB METRICS (labelled by page size)
+        (
+            "hugetlb_usage_bytes",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The major number of the device.",
+                help: "Current usage of hugetlb pages of the given size, in bytes.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_sectors_minor",
+            "hugetlb_limit_bytes",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The minor number of the device.",
+                help: "Limit of hugetlb pages of the given size, in bytes. Not reported if there is no limit.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_sectors_data",
+            "hugetlb_max_usage_bytes",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The sector count transferred.",
+                help: "Maximum recorded usage of hugetlb pages of the given size, in bytes (cgroup v1 only).",
                 labels: vec![],
                 rename: None,
             },
         ),
-        // Field: sectors_recursive: Vec<BlkIoData>
-        // Nested BlkIoData fields prefixed with "blkio_sectors_recursive_"
         (
-            "blkio_sectors_recursive_major",
+            "hugetlb_failcnt",
+            MetricDescriptor {
+                metric_type: MetricType::Counter,
+                help: "Number of allocation failures because of the hugetlb limit of the given size.",
+                labels: vec![],
+                rename: Some("hugetlb_failcnt_total"),
+            },
+        ),
+        // CGROUP CPU LIMIT METRICS
+        (
+            "cpu_limit_quota_usec",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The major number of the device.",
+                help: "CPU time in microseconds the cgroup may use in each period. Not reported if there is no quota.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_sectors_recursive_minor",
+            "cpu_limit_period_usec",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The minor number of the device.",
+                help: "Length of the CPU quota enforcement period in microseconds.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_sectors_recursive_data",
+            "cpu_limit_weight",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The sector count transferred, including descendant control groups.",
+                help: "Relative CPU weight of the cgroup, from cpu.weight (cgroup v2 only).",
                 labels: vec![],
                 rename: None,
             },
         ),
-        // Field: throttle: BlkIoThrottle
-        // Nested BlkIoThrottle fields prefixed with "blkio_throttle_"
-        // Field: throttle.io_service_bytes: Vec<IoService>
         (
-            "blkio_throttle_io_service_bytes_major",
+            "cpu_limit_shares",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The major number of the device (throttle io_service_bytes).",
+                help: "Relative CPU shares of the cgroup, from cpu.shares (cgroup v1 only).",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_throttle_io_service_bytes_minor",
+            "cpu_limit_cores",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The minor number of the device (throttle io_service_bytes).",
+                help: "Number of CPUs worth of time the cgroup may use, computed as the quota divided by the period. Not reported if there is no quota.",
                 labels: vec![],
                 rename: None,
             },
         ),
+        // CGROUP CPUSET METRICS
         (
-            "blkio_throttle_io_service_bytes_read",
+            "cpuset_cpus_effective_count",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Bytes transferred (read) as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Number of CPUs the cgroup is allowed to run on.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_service_bytes_read_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_throttle_io_service_bytes_write",
+            "cpuset_mems_effective_count",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Bytes transferred (write) as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Number of memory nodes the cgroup is allowed to allocate memory from.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_service_bytes_write_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_throttle_io_service_bytes_sync",
+            "cpuset_info",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Synchronous bytes transferred as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Always 1. The cpus and mems labels hold the effective CPU and memory node lists of the cgroup.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_service_bytes_sync_total"),
+                rename: None,
             },
         ),
+        // CGROUP CORE METRICS (cgroup v2 only)
         (
-            "blkio_throttle_io_service_bytes_async",
+            "stat_nr_descendants",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Asynchronous bytes transferred as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Number of visible descendant cgroups.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_service_bytes_async_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_throttle_io_service_bytes_discard",
+            "stat_nr_dying_descendants",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Discarded bytes as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Number of dying descendant cgroups, which were deleted but are still kept around by the kernel.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_service_bytes_discard_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_throttle_io_service_bytes_total",
+            "events_populated",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Total bytes transferred as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "1 if the cgroup or its descendants contain any live processes, 0 otherwise.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_service_bytes_total"),
+                rename: None,
             },
         ),
-        // Field: throttle.io_service_bytes_total: u64
         (
-            "blkio_throttle_io_service_bytes_total",
+            "events_frozen",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Total amount of bytes transferred as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "1 if the cgroup is frozen, 0 otherwise.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_service_bytes_total"),
+                rename: None,
             },
         ),
-        // Field: throttle.io_service_bytes_recursive: Vec<IoService>
+        // CGROUP SWAP METRICS (cgroup v2 only)
         (
-            "blkio_throttle_io_service_bytes_recursive_major",
+            "memory_swap_current",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The major number of the device (throttle io_service_bytes_recursive).",
+                help: "Amount of swap currently used by the cgroup and its descendants, in bytes.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_throttle_io_service_bytes_recursive_minor",
+            "memory_swap_max",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The minor number of the device (throttle io_service_bytes_recursive).",
+                help: "Swap usage hard limit, in bytes. Not reported if there is no limit.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_throttle_io_service_bytes_recursive_read",
+            "memory_swap_peak",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Bytes transferred (read) recursively as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Maximum swap usage recorded for the cgroup and its descendants, in bytes.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_service_bytes_recursive_read_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_throttle_io_service_bytes_recursive_write",
+            "memory_zswap_current",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Bytes transferred (write) recursively as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Amount of memory consumed by the zswap compression backend, in bytes.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_service_bytes_recursive_write_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_throttle_io_service_bytes_recursive_sync",
+            "memory_zswap_max",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Synchronous bytes transferred recursively as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Zswap usage hard limit, in bytes. Not reported if there is no limit.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_service_bytes_recursive_sync_total"),
+                rename: None,
             },
         ),
+        // CGROUP MEMORY LIMIT METRICS (cgroup v2 only)
         (
-            "blkio_throttle_io_service_bytes_recursive_async",
+            "memory_limit_min",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Asynchronous bytes transferred recursively as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Hard memory protection of the cgroup, from memory.min, in bytes.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_service_bytes_recursive_async_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_throttle_io_service_bytes_recursive_discard",
+            "memory_limit_low",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Discarded bytes recursively as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Best-effort memory protection of the cgroup, from memory.low, in bytes.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_service_bytes_recursive_discard_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_throttle_io_service_bytes_recursive_total",
+            "memory_limit_high",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Total recursive bytes transferred as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Memory usage throttle limit of the cgroup, from memory.high, in bytes. Not reported if there is no limit.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_service_bytes_recursive_total"),
+                rename: None,
             },
         ),
-        // Field: throttle.io_service_bytes_recursive_total: u64
         (
-            "blkio_throttle_io_service_bytes_recursive_total",
+            "memory_limit_max",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Total amount of bytes transferred recursively as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Memory usage hard limit of the cgroup, from memory.max, in bytes. Not reported if there is no limit.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_service_bytes_recursive_total"),
+                rename: None,
             },
         ),
-        // Field: throttle.io_serviced: Vec<IoService>
         (
-            "blkio_throttle_io_serviced_major",
+            "memory_peak",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The major number of the device (throttle io_serviced).",
+                help: "Maximum memory usage recorded for the cgroup and its descendants, in bytes.",
                 labels: vec![],
                 rename: None,
             },
         ),
+        // CGROUP NUMA METRICS (labelled by node and type)
         (
-            "blkio_throttle_io_serviced_minor",
+            "memory_numa_pages",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The minor number of the device (throttle io_serviced).",
+                help: "Number of pages used by the cgroup on the NUMA node, by memory type (cgroup v1 only).",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_throttle_io_serviced_read",
+            "memory_numa_bytes",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Number of I/O operations read as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Amount of memory used by the cgroup on the NUMA node in bytes, by memory type (cgroup v2 only).",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_serviced_read_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_throttle_io_serviced_write",
+            "memory_numa_events",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Number of I/O operations written as seen by the throttle policy.",
+                help: "Number of working set events of the cgroup on the NUMA node, such as refaults, by event type (cgroup v2 only).",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_serviced_write_total"),
+                rename: Some("memory_numa_events_total"),
             },
         ),
+        // CGROUP IO LIMIT METRICS (cgroup v2 only, labelled by device)
         (
-            "blkio_throttle_io_serviced_sync",
+            "io_max_rbps",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Synchronous I/O operations as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Read bandwidth limit of the device, in bytes per second.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_serviced_sync_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_throttle_io_serviced_async",
+            "io_max_wbps",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Asynchronous I/O operations as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Write bandwidth limit of the device, in bytes per second.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_serviced_async_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_throttle_io_serviced_discard",
+            "io_max_riops",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Discarded I/O operations as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Read operations per second limit of the device.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_serviced_discard_total"),
+                rename: None,
             },
         ),
         (
-            "blkio_throttle_io_serviced_total",
+            "io_max_wiops",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "Total I/O operations as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Write operations per second limit of the device.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_serviced_total"),
+                rename: None,
             },
         ),
-        // Field: throttle.io_serviced_total: u64
         (
-            "blkio_throttle_io_serviced_total",
+            "io_weight",
             MetricDescriptor {
-                metric_type: MetricType::Counter,
-                help: "The total number of I/O operations performed as seen by the throttle policy.",
+                metric_type: MetricType::Gauge,
+                help: "Relative I/O weight of the cgroup for the device, overriding the default weight.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_serviced_total"),
+                rename: None,
             },
         ),
-        // Field: throttle.io_serviced_recursive: Vec<IoService>
         (
-            "blkio_throttle_io_serviced_recursive_major",
+            "io_weight_default",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The major number of the device (throttle io_serviced_recursive).",
+                help: "Default relative I/O weight of the cgroup for all devices.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_throttle_io_serviced_recursive_minor",
+            "io_latency_target_usec",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The minor number of the device (throttle io_serviced_recursive).",
+                help: "I/O latency target of the cgroup for the device, in microseconds.",
                 labels: vec![],
                 rename: None,
             },
         ),
+        // CGROUP PER-CPU METRICS (cgroup v1 only, labelled by cpu)
         (
-            "blkio_throttle_io_serviced_recursive_read",
+            "cpu_usage_seconds",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Number of I/O operations read recursively as seen by the throttle policy.",
+                help: "CPU time consumed on the CPU by the cgroup's tasks, in seconds (cgroup v1 only).",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_serviced_recursive_read_total"),
+                rename: Some("cpu_usage_seconds_total"),
             },
         ),
         (
-            "blkio_throttle_io_serviced_recursive_write",
+            "cpu_user_seconds",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Number of I/O operations written recursively as seen by the throttle policy.",
+                help: "User CPU time consumed on the CPU by the cgroup's tasks, in seconds (cgroup v1 only).",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_serviced_recursive_write_total"),
+                rename: Some("cpu_user_seconds_total"),
             },
         ),
         (
-            "blkio_throttle_io_serviced_recursive_sync",
+            "cpu_system_seconds",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Synchronous I/O operations recursively as seen by the throttle policy.",
+                help: "System CPU time consumed on the CPU by the cgroup's tasks, in seconds (cgroup v1 only).",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_serviced_recursive_sync_total"),
+                rename: Some("cpu_system_seconds_total"),
             },
         ),
+        // Per-device blkio metrics, labelled by "device", "major_minor" and "op"
         (
-            "blkio_throttle_io_serviced_recursive_async",
+            "blkio_device_io_merged",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Asynchronous I/O operations recursively as seen by the throttle policy.",
+                help: "Number of BIOs merged into requests for I/O operations on the device, by operation.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_serviced_recursive_async_total"),
+                rename: Some("blkio_device_io_merged_total"),
             },
         ),
         (
-            "blkio_throttle_io_serviced_recursive_discard",
+            "blkio_device_io_merged_recursive",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Discarded I/O operations recursively as seen by the throttle policy.",
+                help: "Number of BIOs merged into requests on the device, by operation, including descendant control groups.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_serviced_recursive_discard_total"),
+                rename: Some("blkio_device_io_merged_recursive_total"),
             },
         ),
         (
-            "blkio_throttle_io_serviced_recursive_total",
+            "blkio_device_io_queued",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Number of requests queued for I/O operations on the device, by operation.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "blkio_device_io_queued_recursive",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Number of requests queued for I/O on the device, by operation, including descendant control groups.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        (
+            "blkio_device_io_service_bytes",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Total recursive I/O operations as seen by the throttle policy.",
+                help: "Number of bytes transferred to or from the device, by operation.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_serviced_recursive_total"),
+                rename: Some("blkio_device_io_service_bytes_total"),
             },
         ),
-        // Field: throttle.io_serviced_recursive_total: u64
         (
-            "blkio_throttle_io_serviced_recursive_total",
+            "blkio_device_io_service_bytes_recursive",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "Total number of recursive I/O operations as seen by the throttle policy.",
+                help: "Number of bytes transferred to or from the device, by operation, including descendant control groups.",
                 labels: vec![],
-                rename: Some("blkio_throttle_io_serviced_recursive_total"),
+                rename: Some("blkio_device_io_service_bytes_recursive_total"),
             },
         ),
-        // Field: throttle.read_bps_device: Vec<BlkIoData>
-        // Nested BlkIoData fields prefixed with "blkio_throttle_read_bps_device_"
         (
-            "blkio_throttle_read_bps_device_major",
+            "blkio_device_io_serviced",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The major number of the device (read BPS).",
+                metric_type: MetricType::Counter,
+                help: "Number of I/O operations issued to the device, by operation.",
                 labels: vec![],
-                rename: None,
+                rename: Some("blkio_device_io_serviced_total"),
             },
         ),
         (
-            "blkio_throttle_read_bps_device_minor",
+            "blkio_device_io_serviced_recursive",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The minor number of the device (read BPS).",
+                metric_type: MetricType::Counter,
+                help: "Number of I/O operations issued to the device, by operation, including descendant control groups.",
                 labels: vec![],
-                rename: None,
+                rename: Some("blkio_device_io_serviced_recursive_total"),
             },
         ),
         (
-            "blkio_throttle_read_bps_device_data",
+            "blkio_device_io_service_time",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The upper limit of bytes per second rate of read operations.",
+                metric_type: MetricType::Counter,
+                help: "Time between request dispatch and request completion for I/O operations on the device, in nanoseconds, by operation.",
                 labels: vec![],
-                rename: None,
+                rename: Some("blkio_device_io_service_time_total"),
             },
         ),
-        // Field: throttle.read_iops_device: Vec<BlkIoData>
-        // Nested BlkIoData fields prefixed with "blkio_throttle_read_iops_device_"
         (
-            "blkio_throttle_read_iops_device_major",
+            "blkio_device_io_service_time_recursive",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The major number of the device (read IOPS).",
+                metric_type: MetricType::Counter,
+                help: "I/O service time on the device in nanoseconds, by operation, including descendant control groups.",
                 labels: vec![],
-                rename: None,
+                rename: Some("blkio_device_io_service_time_recursive_total"),
             },
         ),
         (
-            "blkio_throttle_read_iops_device_minor",
+            "blkio_device_io_wait_time",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The minor number of the device (read IOPS).",
+                metric_type: MetricType::Counter,
+                help: "Time I/O operations on the device spent waiting in the scheduler queues, in nanoseconds, by operation.",
                 labels: vec![],
-                rename: None,
+                rename: Some("blkio_device_io_wait_time_total"),
             },
         ),
         (
-            "blkio_throttle_read_iops_device_data",
+            "blkio_device_io_wait_time_recursive",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The upper limit of I/O operations per second for read operations.",
+                metric_type: MetricType::Counter,
+                help: "I/O wait time on the device in nanoseconds, by operation, including descendant control groups.",
                 labels: vec![],
-                rename: None,
+                rename: Some("blkio_device_io_wait_time_recursive_total"),
             },
         ),
-        // Field: throttle.write_bps_device: Vec<BlkIoData>
-        // Nested BlkIoData fields prefixed with "blkio_throttle_write_bps_device_"
         (
-            "blkio_throttle_write_bps_device_major",
+            "blkio_device_throttle_io_service_bytes",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The major number of the device (write BPS).",
+                metric_type: MetricType::Counter,
+                help: "Number of bytes transferred to or from the device as seen by the throttling policy, by operation.",
                 labels: vec![],
-                rename: None,
+                rename: Some("blkio_device_throttle_io_service_bytes_total"),
             },
         ),
         (
-            "blkio_throttle_write_bps_device_minor",
+            "blkio_device_throttle_io_service_bytes_recursive",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The minor number of the device (write BPS).",
+                metric_type: MetricType::Counter,
+                help: "Bytes transferred on the device as seen by the throttling policy, by operation, including descendant control groups.",
                 labels: vec![],
-                rename: None,
+                rename: Some("blkio_device_throttle_io_service_bytes_recursive_total"),
             },
         ),
         (
-            "blkio_throttle_write_bps_device_data",
+            "blkio_device_throttle_io_serviced",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The upper limit of bytes per second rate of write operations.",
+                metric_type: MetricType::Counter,
+                help: "Number of I/O operations issued to the device as seen by the throttling policy, by operation.",
                 labels: vec![],
-                rename: None,
+                rename: Some("blkio_device_throttle_io_serviced_total"),
             },
         ),
-        // Field: throttle.write_iops_device: Vec<BlkIoData>
-        // Nested BlkIoData fields prefixed with "blkio_throttle_write_iops_device_"
         (
-            "blkio_throttle_write_iops_device_major",
+            "blkio_device_throttle_io_serviced_recursive",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The major number of the device (write IOPS).",
+                metric_type: MetricType::Counter,
+                help: "I/O operations on the device as seen by the throttling policy, by operation, including descendant control groups.",
                 labels: vec![],
-                rename: None,
+                rename: Some("blkio_device_throttle_io_serviced_recursive_total"),
             },
         ),
         (
-            "blkio_throttle_write_iops_device_minor",
+            "blkio_device_sectors",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The minor number of the device (write IOPS).",
+                metric_type: MetricType::Counter,
+                help: "Number of sectors transferred to or from the device.",
                 labels: vec![],
-                rename: None,
+                rename: Some("blkio_device_sectors_total"),
             },
         ),
         (
-            "blkio_throttle_write_iops_device_data",
+            "blkio_device_sectors_recursive",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The upper limit of I/O operations per second for write operations.",
+                metric_type: MetricType::Counter,
+                help: "Number of sectors transferred to or from the device, including descendant control groups.",
                 labels: vec![],
-                rename: None,
+                rename: Some("blkio_device_sectors_recursive_total"),
             },
         ),
-        // End of throttle fields
-        // Field: time: Vec<BlkIoData>
-        // Nested BlkIoData fields prefixed with "blkio_time_"
         (
-            "blkio_time_major",
+            "blkio_device_time",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The major number of the device (time).",
+                metric_type: MetricType::Counter,
+                help: "Disk time allocated to the cgroup on the device, in milliseconds.",
                 labels: vec![],
-                rename: None,
+                rename: Some("blkio_device_time_total"),
             },
         ),
         (
-            "blkio_time_minor",
+            "blkio_device_time_recursive",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The minor number of the device (time).",
+                metric_type: MetricType::Counter,
+                help: "Disk time allocated to the cgroup on the device in milliseconds, including descendant control groups.",
                 labels: vec![],
-                rename: None,
+                rename: Some("blkio_device_time_recursive_total"),
             },
         ),
         (
-            "blkio_time_data",
+            "blkio_device_weight",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The total time the control group had access to the I/O devices.",
+                help: "Relative I/O weight of the cgroup for the device, overriding the default weight.",
                 labels: vec![],
                 rename: None,
             },
         ),
-        // Field: time_recursive: Vec<BlkIoData>
-        // Nested BlkIoData fields prefixed with "blkio_time_recursive_"
         (
-            "blkio_time_recursive_major",
+            "blkio_device_leaf_weight",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The major number of the device (time recursive).",
+                help: "Relative I/O weight of the cgroup's own tasks for the device when competing against descendant control groups.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_time_recursive_minor",
+            "blkio_device_throttle_read_bps",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The minor number of the device (time recursive).",
+                help: "Read bandwidth limit of the device, in bytes per second.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_time_recursive_data",
+            "blkio_device_throttle_read_iops",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The total time the control group had access to the I/O devices, including descendants.",
+                help: "Read operations per second limit of the device.",
                 labels: vec![],
                 rename: None,
             },
         ),
-        // Field: weight: u64
         (
-            "blkio_weight",
+            "blkio_device_throttle_write_bps",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The weight of this control group.",
+                help: "Write bandwidth limit of the device, in bytes per second.",
                 labels: vec![],
                 rename: None,
             },
         ),
-        // Field: weight_device: Vec<BlkIoData>
-        // Nested BlkIoData fields prefixed with "blkio_weight_device_"
         (
-            "blkio_weight_device_major",
+            "blkio_device_throttle_write_iops",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The major number of the device (weight).",
+                help: "Write operations per second limit of the device.",
                 labels: vec![],
                 rename: None,
             },
         ),
+        // Freezer state and creation time
         (
-            "blkio_weight_device_minor",
+            "freezer_state",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The minor number of the device (weight).",
+                help: "Whether the freezer of the cgroup is in the state given by the `state` label, either 0 or 1.",
                 labels: vec![],
                 rename: None,
             },
         ),
         (
-            "blkio_weight_device_data",
+            "created_timestamp_seconds",
             MetricDescriptor {
                 metric_type: MetricType::Gauge,
-                help: "The weight data associated with the device.",
+                help: "Time the cgroup was created, in seconds since the Unix epoch.",
                 labels: vec![],
                 rename: None,
             },
         ),
-        // Field: io_stat: Vec<IoStat>
-        // Nested IoStat fields prefixed with "blkio_io_stat_"
+        // Per-interface network metrics, labelled by "interface" and "netns"
         (
-            "blkio_io_stat_major",
+            "network_receive_bytes",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The major number of the device (io_stat).",
+                metric_type: MetricType::Counter,
+                help: "Number of bytes received on the interface.",
                 labels: vec![],
-                rename: None,
+                rename: Some("network_receive_bytes_total"),
             },
         ),
         (
-            "blkio_io_stat_minor",
+            "network_receive_packets",
             MetricDescriptor {
-                metric_type: MetricType::Gauge,
-                help: "The minor number of the device (io_stat).",
+                metric_type: MetricType::Counter,
+                help: "Number of packets received on the interface.",
                 labels: vec![],
-                rename: None,
+                rename: Some("network_receive_packets_total"),
             },
         ),
         (
-            "blkio_io_stat_rbytes",
+            "network_receive_errors",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many bytes were read from the device.",
+                help: "Number of receive errors on the interface.",
                 labels: vec![],
-                rename: Some("blkio_io_stat_rbytes_total"),
+                rename: Some("network_receive_errors_total"),
             },
         ),
         (
-            "blkio_io_stat_wbytes",
+            "network_receive_drops",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many bytes were written to the device.",
+                help: "Number of received packets dropped on the interface.",
                 labels: vec![],
-                rename: Some("blkio_io_stat_wbytes_total"),
+                rename: Some("network_receive_drops_total"),
             },
         ),
         (
-            "blkio_io_stat_rios",
+            "network_transmit_bytes",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many IOPS were read from the device.",
+                help: "Number of bytes transmitted on the interface.",
                 labels: vec![],
-                rename: Some("blkio_io_stat_rios_total"),
+                rename: Some("network_transmit_bytes_total"),
             },
         ),
         (
-            "blkio_io_stat_wios",
+            "network_transmit_packets",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many IOPS were written to the device.",
+                help: "Number of packets transmitted on the interface.",
                 labels: vec![],
-                rename: Some("blkio_io_stat_wios_total"),
+                rename: Some("network_transmit_packets_total"),
             },
         ),
         (
-            "blkio_io_stat_dbytes",
+            "network_transmit_errors",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many discard bytes were read from the device.",
+                help: "Number of transmit errors on the interface.",
                 labels: vec![],
-                rename: Some("blkio_io_stat_dbytes_total"),
+                rename: Some("network_transmit_errors_total"),
             },
         ),
         (
-            "blkio_io_stat_dios",
+            "network_transmit_drops",
             MetricDescriptor {
                 metric_type: MetricType::Counter,
-                help: "How many discard IOPS were written to the device.",
+                help: "Number of transmitted packets dropped on the interface.",
+                labels: vec![],
+                rename: Some("network_transmit_drops_total"),
+            },
+        ),
+        // Derived working set memory
+        (
+            "memory_working_set_bytes",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Memory usage of the cgroup minus inactive file-backed memory, in bytes. This is the working set as computed by cAdvisor and used by the Kubernetes eviction manager.",
+                labels: vec![],
+                rename: None,
+            },
+        ),
+        // CPU rates computed between scrapes
+        (
+            "cpu_throttled_ratio",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "Share of the CPU enforcement periods since the previous scrape in which the cgroup was throttled, between 0 and 1.",
                 labels: vec![],
-                rename: Some("blkio_io_stat_dios_total"),
+                rename: None,
+            },
+        ),
+        (
+            "cpu_limit_utilization",
+            MetricDescriptor {
+                metric_type: MetricType::Gauge,
+                help: "CPU usage since the previous scrape divided by the CPU quota of the cgroup, where 1 means the whole quota was used.",
+                labels: vec![],
+                rename: None,
             },
         ),
     ])
-});
+}
@@ -78,6 +78,9 @@ fn process_process(
             });
             let mut process = process.clone();
             process.gather_remaining_info()?;
+            if config.metrics.smaps_rollup {
+                process.gather_smaps_rollup();
+            }
             group.insert(process);
         }
     }
@@ -1,13 +1,24 @@
-use super::Proc;
-use crate::render::Named;
-use procfs::WithCurrentSystemInfo as _;
+use super::{Proc, smaps::SmapsRollup};
+use crate::render::{LabeledSample, Named};
+use procfs::{WithCurrentSystemInfo as _, process::LimitValue};
 use saturating_cast::SaturatingCast as _;
 use serde::Serialize;
 
+/// The process states that are counted by `num_procs_by_state`: running, sleeping, waiting on
+/// disk (uninterruptible), zombie and stopped.
+const PROCESS_STATES: [char; 5] = ['R', 'S', 'D', 'Z', 'T'];
+
+const NANOSECONDS_PER_SECOND: f64 = 1_000_000_000.0;
+
+/// The memory sizes in `/proc/<pid>/status` are in kibibytes.
+const BYTES_PER_KIB: u64 = 1024;
+
 #[derive(Serialize, Default, Debug, Clone)]
 pub struct ProcessMetrics {
     #[serde(skip)]
     pub name: String,
+    #[serde(skip)]
+    pub labeled_samples: Vec<LabeledSample>,
 
     pub rss: u64,
     pub utime: f64,
@@ -15,17 +26,38 @@ pub struct ProcessMetrics {
     pub cpu_seconds_total: f64,
     pub memory_usage_bytes: u64,
     pub num_fds: u64,
+    /// The lowest `RLIMIT_NOFILE` of the processes. `None` if they are all unlimited.
+    pub fd_soft_limit: Option<u64>,
+    pub fd_hard_limit: Option<u64>,
+    /// The highest ratio of open file descriptors to the soft limit of a single process.
+    pub max_fd_utilization_ratio: Option<f64>,
     pub num_procs: u64,
     pub num_threads: u64,
     pub io_read_bytes_total: u64,
     pub io_write_bytes_total: u64,
     pub major_page_faults_total: u64,
     pub minor_page_faults_total: u64,
+    pub voluntary_context_switches_total: u64,
+    pub nonvoluntary_context_switches_total: u64,
+    pub schedstat_running_seconds_total: f64,
+    pub schedstat_waiting_seconds_total: f64,
+    pub swap_bytes: u64,
+    pub rss_peak_bytes: u64,
+    pub virtual_memory_peak_bytes: u64,
+    pub locked_memory_bytes: u64,
+    /// Only read if `smaps_rollup` is enabled in the config.
+    pub pss_bytes: Option<u64>,
+    pub uss_bytes: Option<u64>,
+    pub shared_memory_bytes: Option<u64>,
     pub start_time: Option<i64>,
 }
 
 impl ProcessMetrics {
-    #[allow(clippy::similar_names)]
+    #[allow(
+        clippy::similar_names,
+        clippy::too_many_lines,
+        clippy::cast_precision_loss
+    )]
     pub fn from_processes(proc_iter: impl Iterator<Item = Proc>, name: &str) -> Self {
         let name = name.to_string();
         let mut metrics = ProcessMetrics {
@@ -39,11 +71,24 @@ impl ProcessMetrics {
         let mut sum_io_read_bytes = 0;
         let mut sum_io_write_bytes = 0;
         let mut sum_fds = 0;
+        let mut min_fd_soft_limit: Option<u64> = None;
+        let mut min_fd_hard_limit: Option<u64> = None;
+        let mut max_fd_utilization: Option<f64> = None;
         let mut sum_procs = 0;
         let mut sum_threads = 0;
         let mut sum_majflt = 0;
         let mut sum_minflt = 0;
+        let mut sum_voluntary_ctxt_switches = 0;
+        let mut sum_nonvoluntary_ctxt_switches = 0;
+        let mut sum_run_time = 0;
+        let mut sum_run_delay = 0;
+        let mut sum_vmswap = 0;
+        let mut sum_vmhwm = 0;
+        let mut sum_vmpeak = 0;
+        let mut sum_vmlck = 0;
+        let mut sum_smaps_rollup: Option<SmapsRollup> = None;
         let mut min_start_time = None;
+        let mut procs_by_state = [0u64; PROCESS_STATES.len()];
         let page_size = procfs::page_size();
         let clock_tick = procfs::ticks_per_second() as f64;
 
@@ -55,6 +100,9 @@ impl ProcessMetrics {
             sum_threads += stat.num_threads.saturating_cast::<u64>();
             sum_majflt += stat.majflt;
             sum_minflt += stat.minflt;
+            if let Some(index) = PROCESS_STATES.iter().position(|state| *state == stat.state) {
+                procs_by_state[index] += 1;
+            }
             if let Ok(start_time) = stat.starttime().get().map(|t| t.timestamp()) {
                 min_start_time = Some(min_start_time.unwrap_or(i64::MAX).min(start_time));
             }
@@ -63,9 +111,41 @@ impl ProcessMetrics {
                 sum_io_read_bytes += io_stat.read_bytes;
                 sum_io_write_bytes += io_stat.write_bytes;
             }
+            if let Some(totals) = process.task_totals() {
+                sum_voluntary_ctxt_switches += totals.voluntary_ctxt_switches;
+                sum_nonvoluntary_ctxt_switches += totals.nonvoluntary_ctxt_switches;
+                sum_run_time += totals.run_time;
+                sum_run_delay += totals.run_delay;
+            }
+            if let Some(status) = process.status() {
+                sum_vmswap += status.vmswap.unwrap_or_default() * BYTES_PER_KIB;
+                sum_vmhwm += status.vmhwm.unwrap_or_default() * BYTES_PER_KIB;
+                sum_vmpeak += status.vmpeak.unwrap_or_default() * BYTES_PER_KIB;
+                sum_vmlck += status.vmlck.unwrap_or_default() * BYTES_PER_KIB;
+            }
+            if let Some(rollup) = process.smaps_rollup() {
+                let sum = sum_smaps_rollup.get_or_insert_with(SmapsRollup::default);
+                sum.pss += rollup.pss;
+                sum.private += rollup.private;
+                sum.shared += rollup.shared;
+            }
             if let Some(fd_count) = process.fd_count() {
                 sum_fds += *fd_count as u64;
             }
+            if let Some(fd_limit) = process.fd_limit() {
+                if let LimitValue::Value(soft) = fd_limit.soft_limit {
+                    min_fd_soft_limit = Some(min_fd_soft_limit.map_or(soft, |min| min.min(soft)));
+                    if let Some(fd_count) = process.fd_count() {
+                        let utilization = *fd_count as f64 / soft.max(1) as f64;
+                        max_fd_utilization = Some(
+                            max_fd_utilization.map_or(utilization, |max| max.max(utilization)),
+                        );
+                    }
+                }
+                if let LimitValue::Value(hard) = fd_limit.hard_limit {
+                    min_fd_hard_limit = Some(min_fd_hard_limit.map_or(hard, |min| min.min(hard)));
+                }
+            }
             sum_procs += 1;
         }
         metrics.rss = sum_rss_of_procs;
@@ -74,13 +154,38 @@ impl ProcessMetrics {
         metrics.stime = sum_stime;
         metrics.cpu_seconds_total = sum_utime + sum_stime;
         metrics.num_fds = sum_fds;
+        metrics.fd_soft_limit = min_fd_soft_limit;
+        metrics.fd_hard_limit = min_fd_hard_limit;
+        metrics.max_fd_utilization_ratio = max_fd_utilization;
         metrics.num_procs = sum_procs;
         metrics.num_threads = sum_threads;
         metrics.io_read_bytes_total = sum_io_read_bytes;
         metrics.io_write_bytes_total = sum_io_write_bytes;
         metrics.major_page_faults_total = sum_majflt;
         metrics.minor_page_faults_total = sum_minflt;
+        metrics.voluntary_context_switches_total = sum_voluntary_ctxt_switches;
+        metrics.nonvoluntary_context_switches_total = sum_nonvoluntary_ctxt_switches;
+        metrics.schedstat_running_seconds_total = sum_run_time as f64 / NANOSECONDS_PER_SECOND;
+        metrics.schedstat_waiting_seconds_total = sum_run_delay as f64 / NANOSECONDS_PER_SECOND;
+        metrics.swap_bytes = sum_vmswap;
+        metrics.rss_peak_bytes = sum_vmhwm;
+        metrics.virtual_memory_peak_bytes = sum_vmpeak;
+        metrics.locked_memory_bytes = sum_vmlck;
+        metrics.pss_bytes = sum_smaps_rollup.as_ref().map(|sum| sum.pss);
+        metrics.uss_bytes = sum_smaps_rollup.as_ref().map(|sum| sum.private);
+        metrics.shared_memory_bytes = sum_smaps_rollup.as_ref().map(|sum| sum.shared);
         metrics.start_time = min_start_time;
+        metrics.labeled_samples = PROCESS_STATES
+            .iter()
+            .zip(procs_by_state)
+            .map(|(state, count)| {
+                LabeledSample::new(
+                    "num_procs_by_state",
+                    vec![("state", state.to_string())],
+                    count,
+                )
+            })
+            .collect();
         metrics
     }
 }
@@ -89,4 +194,8 @@ impl Named for ProcessMetrics {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn labeled_samples(&self) -> &[LabeledSample] {
+        &self.labeled_samples
+    }
 }
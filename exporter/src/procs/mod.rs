@@ -1,10 +1,12 @@
 mod discover;
 mod metrics;
+mod smaps;
 
 use derive_getters::Getters;
 pub use discover::discover_procs_metrics;
 pub use metrics::ProcessMetrics;
 use procfs::ProcResult;
+use smaps::SmapsRollup;
 
 use crate::matcher::ProcessMatcher;
 
@@ -18,7 +20,12 @@ pub struct Proc {
     cmdline: String,
     stat: procfs::process::Stat,
     io: Option<procfs::process::Io>,
+    status: Option<procfs::process::Status>,
+    task_totals: Option<TaskTotals>,
+    smaps_rollup: Option<SmapsRollup>,
     fd_count: Option<usize>,
+    /// `RLIMIT_NOFILE`, the limit on the number of open file descriptors.
+    fd_limit: Option<procfs::process::Limit>,
 }
 
 impl TryFrom<procfs::process::Process> for Proc {
@@ -42,7 +49,11 @@ impl TryFrom<procfs::process::Process> for Proc {
             stat,
             // These fields will be gathered later once a process passes the matcher.
             io: None,
+            status: None,
+            task_totals: None,
+            smaps_rollup: None,
             fd_count: None,
+            fd_limit: None,
         })
     }
 }
@@ -65,7 +76,11 @@ impl Proc {
             cmdline,
             stat,
             io,
+            status: None,
+            task_totals: None,
+            smaps_rollup: None,
             fd_count,
+            fd_limit: None,
         }
     }
 
@@ -84,7 +99,51 @@ impl Proc {
 
     pub fn gather_remaining_info(&mut self) -> ProcResult<()> {
         self.io = Some(procfs::process::Process::new(self.pid)?.io()?);
+        self.status = Some(procfs::process::Process::new(self.pid)?.status()?);
+        self.task_totals = Some(TaskTotals::read(&procfs::process::Process::new(self.pid)?)?);
         self.fd_count = Some(procfs::process::Process::new(self.pid)?.fd_count()?);
+        self.fd_limit = procfs::process::Process::new(self.pid)?
+            .limits()
+            .ok()
+            .map(|limits| limits.max_open_files);
         Ok(())
     }
+
+    /// Reads `/proc/<pid>/smaps_rollup`, which is expensive, so it is opt-in per matcher.
+    pub fn gather_smaps_rollup(&mut self) {
+        self.smaps_rollup = SmapsRollup::read(self.pid);
+    }
+}
+
+/// Counters that `/proc/<pid>/status` and `/proc/<pid>/schedstat` only report for the main
+/// thread, summed over all threads of the process from `/proc/<pid>/task/<tid>`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TaskTotals {
+    pub voluntary_ctxt_switches: u64,
+    pub nonvoluntary_ctxt_switches: u64,
+    /// Time spent on the CPU in nanoseconds.
+    pub run_time: u64,
+    /// Time spent waiting for a CPU while runnable, in nanoseconds.
+    pub run_delay: u64,
+}
+
+impl TaskTotals {
+    fn read(process: &procfs::process::Process) -> ProcResult<Self> {
+        let mut totals = Self::default();
+        // Threads that exit while the process is being read are skipped.
+        for task in process.tasks()?.filter_map(Result::ok) {
+            if let Ok(status) = task.status() {
+                totals.voluntary_ctxt_switches +=
+                    status.voluntary_ctxt_switches.unwrap_or_default();
+                totals.nonvoluntary_ctxt_switches +=
+                    status.nonvoluntary_ctxt_switches.unwrap_or_default();
+            }
+            // `schedstat` is missing if the kernel is built without `CONFIG_SCHED_INFO`.
+            if let Ok(schedstat) = task.schedstat() {
+                totals.run_time += schedstat.sum_exec_runtime;
+                totals.run_delay += schedstat.run_delay;
+            }
+        }
+        Ok(totals)
+    }
 }
@@ -0,0 +1,75 @@
+use std::fs;
+
+/// Memory usage of a process summed over all of its mappings, from `/proc/<pid>/smaps_rollup`.
+/// All values are in bytes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SmapsRollup {
+    /// Proportional set size: the private memory plus an even share of the shared memory.
+    pub pss: u64,
+    /// Memory that is only mapped by this process, i.e. the unique set size.
+    pub private: u64,
+    /// Memory that is also mapped by other processes.
+    pub shared: u64,
+}
+
+impl SmapsRollup {
+    /// Reads the rollup of a process. The kernel walks all mappings of the process to produce
+    /// it, so it is only read when enabled in the config.
+    pub fn read(pid: i32) -> Option<Self> {
+        fs::read_to_string(format!("/proc/{pid}/smaps_rollup"))
+            .ok()
+            .map(|content| parse_smaps_rollup(&content))
+    }
+}
+
+fn parse_smaps_rollup(content: &str) -> SmapsRollup {
+    let mut rollup = SmapsRollup::default();
+    for line in content.lines() {
+        // Each line looks like `Pss:  1234 kB`, after a header line with the address range.
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let Some(kib) = value
+            .split_whitespace()
+            .next()
+            .and_then(|value| value.parse::<u64>().ok())
+        else {
+            continue;
+        };
+        let bytes = kib * 1024;
+        match key {
+            "Pss" => rollup.pss = bytes,
+            "Private_Clean" | "Private_Dirty" => rollup.private += bytes,
+            "Shared_Clean" | "Shared_Dirty" => rollup.shared += bytes,
+            _ => {}
+        }
+    }
+    rollup
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_rollup() {
+        let content = "5583f2b8e000-7ffd3c5f6000 ---p 00000000 00:00 0                          [rollup]\n\
+                       Rss:               10240 kB\n\
+                       Pss:                6144 kB\n\
+                       Pss_Anon:           4096 kB\n\
+                       Shared_Clean:       4096 kB\n\
+                       Shared_Dirty:       1024 kB\n\
+                       Private_Clean:      1024 kB\n\
+                       Private_Dirty:      4096 kB\n\
+                       Swap:                  0 kB\n";
+        assert_eq!(
+            parse_smaps_rollup(content),
+            SmapsRollup {
+                pss: 6144 * 1024,
+                private: 5120 * 1024,
+                shared: 5120 * 1024,
+            }
+        );
+    }
+}
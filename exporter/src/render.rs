@@ -2,7 +2,8 @@ use std::{collections::HashMap, io::Write as _};
 
 use anyhow::Context as _;
 use bytes::{BufMut as _, BytesMut};
-use serde::Serialize;
+use derive_more::From;
+use serde::{Serialize, ser::SerializeStruct as _};
 use serde_prom::MetricDescriptor;
 
 use cgroups_exporter_config::MetricsConfig;
@@ -58,8 +59,20 @@ impl<'s> MetricsRenderer<'s> {
                 .label_map
                 .get(NAME_LABEL)
                 .map_or_else(|| NAME_LABEL.to_string(), std::borrow::ToOwned::to_owned);
-            serializer.set_current_labels(vec![(name_label, metric_name)]);
-            metric.serialize(serializer)?;
+            serializer.set_current_labels(vec![(name_label.clone(), metric_name.clone())]);
+            metric.serialize(&mut *serializer)?;
+
+            for sample in metric.labeled_samples() {
+                let mut labels = vec![(name_label.clone(), metric_name.clone())];
+                labels.extend(
+                    sample
+                        .labels
+                        .iter()
+                        .map(|(key, value)| ((*key).to_string(), value.clone())),
+                );
+                serializer.set_current_labels(labels);
+                sample.serialize(&mut *serializer)?;
+            }
         }
 
         Ok(())
@@ -103,4 +116,52 @@ impl<T> MatchGroup<T> {
 
 pub trait Named {
     fn name(&self) -> &str;
+
+    /// Samples that carry labels in addition to the name label, rendered after the group's fields.
+    fn labeled_samples(&self) -> &[LabeledSample] {
+        &[]
+    }
+}
+
+/// A single sample with its own labels, such as a device or a NUMA node, for the metrics that
+/// cannot be expressed as plain struct fields.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabeledSample {
+    metric: &'static str,
+    labels: Vec<(&'static str, String)>,
+    value: SampleValue,
+}
+
+impl LabeledSample {
+    pub fn new(
+        metric: &'static str,
+        labels: Vec<(&'static str, String)>,
+        value: impl Into<SampleValue>,
+    ) -> Self {
+        Self {
+            metric,
+            labels,
+            value: value.into(),
+        }
+    }
+}
+
+impl Serialize for LabeledSample {
+    // Serialized as a struct with a single field so it goes through the same naming and metadata
+    // lookup as the rest of the metrics.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("LabeledSample", 1)?;
+        state.serialize_field(self.metric, &self.value)?;
+        state.end()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, From)]
+#[serde(untagged)]
+pub enum SampleValue {
+    Int(u64),
+    Float(f64),
 }
@@ -17,8 +17,8 @@ use tower_http::trace::TraceLayer;
 use tracing::{error, info};
 
 use crate::{
-    cgroups::discover_cgroups_metrics, matcher::MatchableConfig, metadata::METADATA,
-    procs::discover_procs_metrics, render::MetricsRenderer, shell::ShellEvaluator,
+    cgroups::discover_cgroups_metrics, matcher::MatchableConfig, procs::discover_procs_metrics,
+    render::MetricsRenderer, shell::ShellEvaluator,
 };
 
 const TIMEOUT_DURATION: Duration = Duration::from_secs(10);
@@ -87,9 +87,8 @@ async fn homepage() -> Html<&'static str> {
 async fn serve_metrics(
     State((config, evaluator)): State<(SharedConfig, ShellEvaluator)>,
 ) -> Result<Response<Body>, AppError> {
-    #[allow(clippy::explicit_auto_deref)]
-    let mut renderer = MetricsRenderer::new(vec![], &*METADATA);
     let config = config.load();
+    let mut renderer = MetricsRenderer::new(vec![], &config.metadata);
     let cgroup_metrics_stream = discover_cgroups_metrics(config.cgroups.as_slice(), &evaluator);
     let proc_metrics_stream = discover_procs_metrics(config.processes.as_slice());
 